@@ -9,20 +9,35 @@
 //! 
 //! Feature Road Map:
 //! - [X] Times Roman font family (normal,bold,italic,bold-italic)
+//! - [X] Helvetica, Courier, Symbol & ZapfDingbats families (Adobe AFM metrics)
+//! - [X] Custom font families registered through the `FontType` trait
 //! - [X] Justify text blocks (left,right,center)
+//! - [X] Justified text (last line alignment, word & letter spacing operators)
+//! - [X] Hard breaks (Shift+Enter ends the line in place, lines ended by one aren't justified)
+//! - [X] Unicode line breaking (words continue across marks, breaks after hyphens, dashes and between ideographs)
+//! - [X] Total fit (Knuth & Plass) line breaking
+//! - [X] Hyphenation (Liang patterns, 30+ languages)
+//! - [X] Widow & orphan control, headings kept with the next block, blocks & list items kept together
+//! - [X] Headers & footers (left/centre/right slots, page numbers, title & date, first page & even page variants)
 //! - [X] Ordered list
+//! - [X] Bullet list
+//! - [X] Nested lists (mixed ordered & bullet)
+//! - [X] Blockquotes (nested content, left rule continued across pages, optional italic)
+//! - [X] Code blocks & inline code (monospaced, whitespace kept, shaded background, long lines wrapped or clipped)
+//! - [X] Syntax highlighting (opt-in `highlight` feature: Rust, JS/TS, Python, JSON, SQL & shell, themed colours)
+//! - [X] Horizontal rules (thickness, colour, solid/dashed/dotted, spacing, moved to the next page rather than below the margin)
 //! - [X] Strikethrough
 //! - [X] Underline
-//! - [X] Header size (H1,H2,H3)
+//! - [X] Heading levels (H1-H6) with a style sheet (size, weight, family, colour, spacing)
+//! - [X] Body font size (tiny - extra large, pt/px/rem/em from `textStyle` marks)
+//! - [X] Text color (hex, rgb(), named)
+//! - [X] Text background highlight
+//! - [X] Link annotation
+//! - [X] Superscript
+//! - [X] Subscript
+//! - [X] Embedded TrueType/OpenType font families (subsetted, metrics from `hmtx`)
 //! - [ ] Digital signatures (0.2.0)
 //! - [ ] Encryption (0.2.0)
-//! - [ ] Body font size (tiny - extra large) (0.3.0)
-//! - [ ] Additional use of embedded font families (0.4.0)
-//! - [ ] Text color (0.5.0)
-//! - [ ] Text background highlight (0.5.0)
-//! - [ ] Link annotation (0.6.0)
-//! - [ ] Superscript (0.7.0)
-//! - [ ] Subscript (0.8.0)
 //! 
 //! ## Links
 //! PDF Writer:
@@ -53,25 +68,360 @@
 //! # Basic Usage
 //! The main entry point is the Doc struct, which is created by Serde from a string slice of JSON output
 //! from Edra. The primary method available on the Doc struct is `.render()` which interfaces with pdf_writer
-//! to return PDF output.
+//! to return the finished PDF as bytes, or a `RenderError` describing why the document couldn't be rendered.
+//! `.render_to()` does the same but writes the PDF straight into any `std::io::Write` destination.
 //! 
 //! ### Simple render
 //! ```
-//! use types::Doc
+//! use edra::types::Doc;
 //! 
 //! // take a JSON string from Edra...
+//! let json_string_from_edra = r#"{
+//!     "type": "doc",
+//!     "content": [
+//!         { "type": "paragraph", "content": [{ "type": "text", "text": "Hello world" }] }
+//!     ]
+//! }"#;
 //! 
 //! // deserialize the json string
 //! let serde_content = serde_json::from_str::<Doc>(json_string_from_edra);
 //! 
 //! // call the .render() method on the doc struct
-//! let pdf_file = match serde_content {
-//!     Ok(mut doc) => doc.render(),
+//! let pdf_file: Vec<u8> = match serde_content {
+//!     Ok(mut doc) => doc.render().expect("render failed"),
 //!     Err(e) => panic!("{e}")
 //! };
 //! 
 //! // ...and write the pdf to output destination
+//! # assert!(pdf_file.starts_with(b"%PDF"));
+//! ```
+//! 
+//! ### Page setup
+//! Every document defaults to A4 portrait with 1/2 inch margins. Other paper sizes, landscape orientation and
+//! individual margins are set with a `PageSetup` before rendering.
+//! ```
+//! use edra::types::{ Doc, Margins, Orientation, PageSetup, PaperSize };
+//! 
+//! let page_setup = PageSetup::new()
+//!     .with_paper_size(PaperSize::Letter)
+//!     .and_orientation(Orientation::Landscape)
+//!     .and_margins(Margins::new(96.0, 48.0, 48.0, 48.0));
+//! 
+//! let mut doc = serde_json::from_str::<Doc>(r#"{
+//!     "type": "doc",
+//!     "content": [
+//!         { "type": "paragraph", "content": [{ "type": "text", "text": "Hello world" }] }
+//!     ]
+//! }"#)
+//!     .expect("invalid json")
+//!     .with_page_setup(page_setup);
+//! 
+//! let pdf_file = doc.render().expect("render failed");
+//! # let content = String::from_utf8_lossy(&pdf_file);
+//! # let start = content.lines().find(|line| line.ends_with(" Td")).expect("no text drawn");
+//! # let (x, y) = match start.split(' ').map(|number| number.parse::<f32>()).collect::<Vec<_>>()[..] {
+//! #     [Ok(x), Ok(y), _] => (x, y),
+//! #     _ => panic!("unexpected text position {start}")
+//! # };
+//! 
+//! // US Letter turned on its side, the first line starts at the left margin and within a line below the top one
+//! assert!(content.contains("/MediaBox [0 0 792 612]"));
+//! assert_eq!(x, 48.0);
+//! assert!((96.0..96.0 + 18.0).contains(&(612.0 - y)));
+//! ```
+//! 
+//! ### Line breaking
+//! Lines are filled greedily unless the total fit (Knuth & Plass) breaker is chosen, which balances the spacing
+//! of every line in a paragraph. Either breaker can hyphenate words with the patterns of a language.
+//! ```
+//! use edra::types::{ Doc, Hyphenation, LineBreaking, Margins, PageSetup, TotalFit };
+//! 
+//! let mut doc = serde_json::from_str::<Doc>(r#"{
+//!     "type": "doc",
+//!     "content": [
+//!         { "type": "paragraph", "content": [{ "type": "text", "text": "The quick brown fox jumps over the lazy dog while the committee deliberates about extraordinarily incomprehensible characteristics of typographical conventions." }] }
+//!     ]
+//! }"#)
+//!     .expect("invalid json")
+//!     .with_page_setup(PageSetup::new().and_margins(Margins::new(48.0, 350.0, 48.0, 48.0)))
+//!     .with_line_breaking(LineBreaking::TotalFit(TotalFit::new().with_tolerance(3.0)))
+//!     .with_hyphenation(Hyphenation::new("en").expect("no patterns for the language"));
+//! 
+//! let pdf_file = doc.render().expect("render failed");
+//! # let contains = |needle: &[u8]| pdf_file.windows(needle.len()).any(|window| window == needle);
+//! 
+//! // the narrow column splits "conventions" across two lines
+//! assert!(contains(b"(-) Tj"));
+//! assert!(contains(b"(tions.) Tj"));
+//! ```
+//! 
+//! ### Words and marks
+//! The text nodes of a block are joined before it is broken into lines, so a word stays whole across marks
+//! (`**Hel**lo`) and punctuation stays with the link in front of it. Breaking whitespace, tabs included, is set
+//! as ordinary spaces.
+//! ```
+//! use edra::types::Doc;
+//! 
+//! let mut doc = serde_json::from_str::<Doc>(r#"{
+//!     "type": "doc",
+//!     "content": [
+//!         { "type": "paragraph", "content": [
+//!             { "type": "text", "marks": [{ "type": "bold" }], "text": "Hel" },
+//!             { "type": "text", "text": "lo, see " },
+//!             { "type": "text", "marks": [{ "type": "link", "attrs": { "href": "https://example.com" } }], "text": "the site" },
+//!             { "type": "text", "text": ". Then\tmore." }
+//!         ] }
+//!     ]
+//! }"#)
+//!     .expect("invalid json");
+//! 
+//! let pdf_file = doc.render().expect("render failed");
+//! # let content = String::from_utf8_lossy(&pdf_file);
+//! # let lines: Vec<&str> = content.lines().filter(|line| !line.ends_with(" Tf")).collect();
+//! # let advance = |text: &str| lines.windows(2).find(|window| window[0] == text).map(|window| window[1]);
+//! 
+//! // bold "Hel" is 18pt wide and "lo," follows it without a gap
+//! assert_eq!(advance("(Hel) Tj"), Some("18 0 Td"));
+//! assert!(content.contains("(lo,) Tj"));
+//! // the full stop follows the 16.668pt wide link text, "Then" is 24.66pt wide plus a 3pt space for the tab
+//! assert_eq!(advance("(site) Tj"), Some("16.668 0 Td"));
+//! assert_eq!(advance("(Then) Tj"), Some("27.66 0 Td"));
+//! ```
+//! 
+//! ### Justified text
+//! Every line of a justified block but the last is stretched to the margin. Drawing lines as continuous text
+//! widened with the word spacing (`Tw`) operator keeps them selectable and searchable as ordinary words.
+//! ```
+//! use edra::types::{ Doc, Justification, JustifySpacing, TextAlignment };
+//! 
+//! let mut doc = serde_json::from_str::<Doc>(r#"{
+//!     "type": "doc",
+//!     "content": [
+//!         { "type": "paragraph", "attrs": { "textAlign": "justify" }, "content": [{ "type": "text", "text": "The quick brown fox jumps over the lazy dog while the committee deliberates about extraordinarily incomprehensible characteristics of typographical conventions, then adjourns until next week." }] }
+//!     ]
+//! }"#)
+//!     .expect("invalid json")
+//!     .with_justification(Justification::new()
+//!         .with_last_line(TextAlignment::Left)
+//!         .and_spacing(JustifySpacing::Operators));
+//! 
+//! let pdf_file = doc.render().expect("render failed");
+//! # let content = String::from_utf8_lossy(&pdf_file);
+//! 
+//! // the first line is widened with `Tw` and the spaces drawn, the last line is left alone
+//! let spacings: Vec<&str> = content.lines().filter(|line| line.ends_with(" Tw")).collect();
+//! 
+//! assert_eq!(spacings.len(), 2);
+//! assert_ne!(spacings[0], "0 Tw");
+//! assert_eq!(spacings[1], "0 Tw");
+//! assert!(content.contains("( ) Tj"));
+//! ```
+//! 
+//! ### Pagination
+//! Blocks are split across pages without leaving fewer than two lines on either page, and headings move to the
+//! next page rather than end one. Any block type, list items included, can be kept on a single page.
+//! ```
+//! use edra::types::{ BlockType, Doc, Pagination };
+//! 
+//! // a page of short paragraphs leaves just enough room at the bottom for the heading
+//! let paragraph = r#"{ "type": "paragraph", "content": [{ "type": "text", "text": "Filler" }] }"#;
+//! let heading = r#"{ "type": "heading", "attrs": { "level": 2 }, "content": [{ "type": "text", "text": "Chapter" }] }"#;
+//! let mut blocks = vec![paragraph; 38];
+//! 
+//! blocks.extend([heading, paragraph]);
+//! 
+//! let mut doc = serde_json::from_str::<Doc>(&format!(r#"{{ "type": "doc", "content": [{}] }}"#, blocks.join(",")))
+//!     .expect("invalid json")
+//!     .with_pagination(Pagination::new()
+//!         .with_orphans(3)
+//!         .and_widows(3)
+//!         .and_keep_together(BlockType::ListItem));
+//! 
+//! let pdf_file = doc.render().expect("render failed");
+//! # let content = String::from_utf8_lossy(&pdf_file);
+//! # let pages: Vec<&str> = content.split("endstream").filter(|page| page.contains(" Tj")).collect();
+//! 
+//! // the heading moves to the top of the second page with the paragraph following it
+//! assert_eq!(pages.len(), 2);
+//! assert!(!pages[0].contains("(Chapter) Tj"));
+//! assert_eq!(pages[1].lines().find(|line| line.ends_with(" Tj")), Some("(Chapter) Tj"));
+//! ```
+//! 
+//! ### Headers and footers
+//! Headers and footers repeat on every page and the body text is shrunk to make room for them. `{page}`, `{pages}`,
+//! `{title}` and `{date}` are filled in on each page.
+//! ```
+//! use edra::types::{ Doc, HeaderFooter, Slots };
+//! 
+//! // enough paragraphs to fill three pages
+//! let paragraph = r#"{ "type": "paragraph", "content": [{ "type": "text", "text": "Revenue grew in every region." }] }"#;
+//! let json = format!(r#"{{ "type": "doc", "content": [{}] }}"#, vec![paragraph; 90].join(","));
+//! 
+//! let mut doc = serde_json::from_str::<Doc>(&json)
+//!     .expect("invalid json")
+//!     .with_title("Annual report")
+//!     .with_header(HeaderFooter::new(Slots::new().with_left("{title}").and_right("{date}"))
+//!         .with_first_page(Slots::new()))
+//!     .with_footer(HeaderFooter::new(Slots::new().and_center("Page {page} of {pages}")));
+//! 
+//! let pdf_file = doc.render().expect("render failed");
+//! # let content = String::from_utf8_lossy(&pdf_file);
+//! 
+//! // the first page has no header, every page has a footer
+//! assert_eq!(content.matches("(Annual report) Tj").count(), 2);
+//! assert!(content.contains("(Page 1 of 3) Tj"));
+//! assert!(content.contains("(Page 3 of 3) Tj"));
+//! ```
+//! 
+//! ### Code blocks
+//! Code blocks are set in Courier on a light grey box, with every space and line kept. Lines too long for the
+//! page wrap with a `»` at their end, or are clipped.
+//! ```
+//! use edra::types::{ CodeOverflow, CodeStyle, Doc };
+//! 
+//! let mut doc = serde_json::from_str::<Doc>(r#"{ "type": "doc", "content": [
+//!         { "type": "codeBlock", "attrs": { "language": "rust" }, "content": [{ "type": "text", "text": "fn main() {\n\tprintln!(\"Hello world\"); // a comment long enough to run past the right edge of the page\n}" }] }
+//!     ] }"#)
+//!     .expect("invalid json")
+//!     .with_code_style(CodeStyle::new().and_overflow(CodeOverflow::Clip));
+//! 
+//! let pdf_file = doc.render().expect("render failed");
+//! # let content = String::from_utf8_lossy(&pdf_file);
+//! # let lines: Vec<&str> = content.lines().collect();
+//! # let starts: Vec<f32> = lines.windows(2)
+//! #     .filter(|window| window[0] == "BT")
+//! #     .filter_map(|window| window[1].split(' ').next()?.parse().ok())
+//! #     .collect();
+//! 
+//! // the tab indents the second line four Courier columns (6pt each), its end is cut off by a clip path at the box
+//! assert_eq!(starts[1] - starts[0], 24.0);
+//! assert!(content.contains("re\nW\nn"));
+//! ```
+//! 
+//! The `highlight` feature colours the tokens of code blocks whose `language` it knows (Rust, JavaScript and
+//! TypeScript, Python, JSON, SQL and shell) with the `HighlightTheme` of the `CodeStyle`. Tokenizing happens
+//! in-process, nothing is downloaded.
+//! ```toml
+//! [dependencies]
+//! edra = { version = "0.1", features = ["highlight"] }
+//! ```
+//! 
+//! ### Font families
+//! Text is set in Times Roman unless another standard 14 family is chosen for the whole document, or for
+//! every block of a given type. Widths come from the Adobe AFM metrics of each font.
+//! ```
+//! use edra::types::{ BlockType, Doc, FontFamily };
+//! 
+//! let mut doc = serde_json::from_str::<Doc>(r#"{
+//!     "type": "doc",
+//!     "content": [
+//!         { "type": "heading", "attrs": { "level": 1 }, "content": [{ "type": "text", "text": "Title" }] },
+//!         { "type": "paragraph", "content": [{ "type": "text", "text": "Body" }] }
+//!     ]
+//! }"#)
+//!     .expect("invalid json")
+//!     .with_font_family(FontFamily::TimesRoman)
+//!     .with_block_font_family(BlockType::Heading, FontFamily::Helvetica);
+//! 
+//! let pdf_file = doc.render().expect("render failed");
+//! # let contains = |needle: &[u8]| pdf_file.windows(needle.len()).any(|window| window == needle);
+//! 
+//! // the heading is set in bold Helvetica, the paragraph in Times Roman
+//! assert!(contains(b"/BaseFont /Helvetica-Bold"));
+//! assert!(contains(b"/BaseFont /Times-Roman"));
+//! ```
+//! 
+//! ### Unicode text
+//! The standard 14 fonts are written with WinAnsiEncoding. Characters it lacks are drawn as the closest one it
+//! has, ligatures as their letters, and anything else (CJK, emoji...) as `?` unless an embedded font is used.
+//! ```
+//! use edra::types::Doc;
+//! 
+//! let mut doc = serde_json::from_str::<Doc>(r#"{
+//!     "type": "doc",
+//!     "content": [
+//!         { "type": "paragraph", "content": [{ "type": "text", "text": "€ – “x” ł 日 ﬁne" }] }
+//!     ]
+//! }"#)
+//!     .expect("invalid json");
+//! 
+//! let pdf_file = doc.render().expect("render failed");
+//! # let content = String::from_utf8_lossy(&pdf_file);
+//! # let shown: Vec<&str> = content.lines().filter_map(|line| line.strip_suffix(" Tj")).collect();
+//! 
+//! // the euro, en dash and curly quotes have WinAnsi codes, `ł` loses its stroke and `日` has no stand-in
+//! assert_eq!(shown[..2], ["<80>", "<96>"]);
+//! assert!(shown[2].starts_with("<93") && shown[2].ends_with("94>"));
+//! assert_eq!(shown[3..], ["(l)", "(?)", "(fine)"]);
+//! ```
+//! 
+//! ### Registered fonts
+//! Any family installed with the PDF viewer can be used by registering its widths (a `FontType`) and the
+//! PostScript names of its variants. Registered fonts are referenced by name and not embedded.
+//! ```
+//! use edra::traits::FontType;
+//! use edra::types::{ Doc, FontFamily, FontVariants };
+//! 
+//! // every glyph of a monospaced font is 600/1000 em wide
+//! struct LetterGothic;
+//! 
+//! impl FontType for LetterGothic {
+//!     fn new() -> Self { LetterGothic }
+//!     fn normal(&self, _ch: &char, font_size: f32) -> f32 { LetterGothic::standardize(600.0, font_size) }
+//!     fn bold(&self, _ch: &char, font_size: f32) -> f32 { LetterGothic::standardize(600.0, font_size) }
+//!     fn italic(&self, _ch: &char, font_size: f32) -> f32 { LetterGothic::standardize(600.0, font_size) }
+//!     fn bold_italic(&self, _ch: &char, font_size: f32) -> f32 { LetterGothic::standardize(600.0, font_size) }
+//!     fn standardize(width: f32, font_size: f32) -> f32 { width * font_size / 1000.0 }
+//! }
+//! 
+//! let mut doc = serde_json::from_str::<Doc>(r#"{
+//!     "type": "doc",
+//!     "content": [
+//!         { "type": "paragraph", "content": [{ "type": "text", "text": "Hello world" }] }
+//!     ]
+//! }"#)
+//!     .expect("invalid json")
+//!     .register_font("letter-gothic", LetterGothic, FontVariants::new("LetterGothic").and_bold("LetterGothic-Bold"))
+//!     .with_font_family(FontFamily::Registered("letter-gothic"));
+//! 
+//! let pdf_file = doc.render().expect("render failed");
+//! # let contains = |needle: &[u8]| pdf_file.windows(needle.len()).any(|window| window == needle);
+//! 
+//! // "Hello " is six 7.2pt wide glyphs at 12pt
+//! assert!(contains(b"/BaseFont /LetterGothic"));
+//! assert!(contains(b"43.2 0 Td"));
+//! ```
+//! 
+//! ### Embedded fonts
+//! A TrueType/OpenType family can replace Times Roman for the whole document. Glyph widths are read from
+//! the font file so line breaking matches the embedded glyphs.
+//! 
+//! The standard fonts only cover WinAnsiEncoding (western European text). Characters outside of it are
+//! substituted (`ł` is drawn as `l`, CJK as `?`), so Polish, Czech, Japanese and other scripts need an
+//! embedded font that contains their glyphs.
+//! ```no_run
+//! use edra::types::{ Doc, EmbeddedFont, TrueTypeFont };
+//! 
+//! let regular = TrueTypeFont::from_bytes(std::fs::read("./Brand-Regular.ttf").unwrap()).unwrap();
+//! let italic = TrueTypeFont::from_bytes(std::fs::read("./Brand-Italic.ttf").unwrap()).unwrap();
+//! 
+//! let mut doc = serde_json::from_str::<Doc>(r#"{ "type": "doc", "content": [] }"#)
+//!     .expect("invalid json")
+//!     .with_embedded_font(EmbeddedFont::new(regular).and_italic(italic));
+//! 
+//! let pdf_file = doc.render().expect("render failed");
+//! ```
+//! 
+//! ### Render to a writer
+//! ```no_run
+//! use edra::types::{ Doc, RenderError };
+//! 
+//! fn save(json_string_from_edra: &str) -> Result<(), Box<dyn std::error::Error>> {
+//!     let mut doc = serde_json::from_str::<Doc>(json_string_from_edra)?;
+//!     let file = std::fs::File::create("./output.pdf")?;
+//! 
+//!     doc.render_to(file).map_err(|e: RenderError| e.into())
+//! }
 //! ```
-//! pub mod types;
 pub mod traits;
 pub mod types;
\ No newline at end of file
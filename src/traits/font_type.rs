@@ -1,37 +1,26 @@
-use crate::types::Style;
+use crate::types::{ FontSlant, FontWeight, TextStyle };
 
 /// Registering new fonts with the document is easy, but time consuming. The character width for each character
-/// must be provided for all font types supported (normal, bold, italic, bold-italic). This can be determined
-/// by calculating the maximum number of characters that can fit an arbitrary page width of 499.0.
+/// must be provided for all font types supported (normal, bold, italic, bold-italic). Widths are usually read from
+/// the font's AFM file or `hmtx` table in 1/1000 em, `standardize` converts them to points for a given font size.
+/// Implementations are registered with `Doc::register_font()`.
 pub trait FontType {
-    fn new() -> Self;
+    fn new() -> Self where Self: Sized;
     fn normal(&self, ch: &char, font_size: f32) -> f32;
     fn bold(&self, ch: &char, font_size: f32) -> f32;
     fn italic(&self, ch: &char, font_size: f32) -> f32;
     fn bold_italic(&self, ch: &char, font_size: f32) -> f32;
-    fn standardize(width: f32, font_size: f32) -> f32;
+    fn standardize(width: f32, font_size: f32) -> f32 where Self: Sized;
 
-    fn char_width(&self, ch: &char, font_style: &Style, font_size: f32) -> f32 {
-        match *font_style {
-            // normal
-            Style::Normal => self.normal(ch, font_size),
-            Style::Underline => self.normal(ch, font_size),
-            Style::Strikethrough => self.normal(ch, font_size),
+    /// width of a character in the variant picked by the style's weight and slant, at the size it is drawn at
+    fn char_width(&self, ch: &char, text_style: &TextStyle) -> f32 {
+        let font_size = text_style.font_size();
 
-            // bold
-            Style::Bold => self.bold(ch,font_size),
-            Style::BoldUnderline => self.bold(ch,font_size),
-            Style::BoldStrikethrough => self.bold(ch,font_size),
-
-            // italic
-            Style::Italic => self.italic(ch,font_size),
-            Style::ItalicUnderline => self.italic(ch,font_size),
-            Style::ItalicStrikethrough => self.italic(ch,font_size),
-
-            // bold & italic
-            Style::BoldItalic => self.bold_italic(ch,font_size),
-            Style::BoldItalicUnderline => self.bold_italic(ch,font_size),
-            Style::BoldItalicStrikethrough => self.bold_italic(ch,font_size),
+        match (text_style.weight, text_style.slant) {
+            (FontWeight::Normal, FontSlant::Normal) => self.normal(ch, font_size),
+            (FontWeight::Bold, FontSlant::Normal) => self.bold(ch, font_size),
+            (FontWeight::Normal, FontSlant::Italic) => self.italic(ch, font_size),
+            (FontWeight::Bold, FontSlant::Italic) => self.bold_italic(ch, font_size),
         }
     }
-}
\ No newline at end of file
+}
@@ -11,8 +11,14 @@ pub struct AttributeField {
     pub class: Option<String>,
     pub tight: Option<bool>,
    #[serde(rename = "start")] 
-    pub list_start: Option<u8>,
+    pub list_start: Option<u32>,
     pub color: Option<String>,
    #[serde(rename = "fontSize")]
-    pub font_size: Option<String>
+    pub font_size: Option<String>,
+    /// destination of a `link` mark
+    pub href: Option<String>,
+    /// browsing context of a `link` mark (`_blank`...), PDF viewers decide where links open so it is not used
+    pub target: Option<String>,
+    /// language of a `codeBlock`
+    pub language: Option<String>
 }
\ No newline at end of file
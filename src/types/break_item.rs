@@ -0,0 +1,219 @@
+use crate::types::Word;
+
+/// Box, glue and penalty items a text block is broken into lines from (Knuth & Plass), built by `Doc::layout_text_block()`
+#[derive(Debug)]
+pub enum BreakItem<'a> {
+    /// words that are never separated, a segment (or hyphenated piece of one) joined across marks
+    Box {
+        words: Vec<Word<'a>>,
+        width: f32,
+    },
+    /// space between segments, the only item that stretches or shrinks
+    /// - an infinite stretch fills the rest of the line (the last line of a paragraph, or a line ended by a hard break)
+    /// - `text` holds the spaces, drawn when lines are written as continuous text
+    Glue {
+        text: &'a str,
+        width: f32,
+        stretch: f32,
+        shrink: f32,
+    },
+    /// break opportunity without a space
+    /// - `f32::INFINITY` forbids the break, `f32::NEG_INFINITY` forces it
+    /// - `hyphen` is drawn at the end of the line when the break is taken, `flagged` breaks end in a hyphen
+    Penalty {
+        penalty: f32,
+        flagged: bool,
+        hyphen: Option<Word<'a>>,
+    },
+}
+
+impl BreakItem<'_> {
+    /// items ending a paragraph, or a line at a hard break, without stretching the words before them
+    pub fn forced_break() -> [Self; 3] {
+        [
+            BreakItem::Penalty { penalty: f32::INFINITY, flagged: false, hyphen: None },
+            BreakItem::Glue { text: "", width: 0.0, stretch: f32::INFINITY, shrink: 0.0 },
+            BreakItem::Penalty { penalty: f32::NEG_INFINITY, flagged: false, hyphen: None },
+        ]
+    }
+
+    /// width added to the line when it is broken here (a hyphen)
+    pub fn break_width(&self) -> f32 {
+        match self {
+            BreakItem::Penalty { hyphen: Some(hyphen), .. } => hyphen.width,
+            _ => 0.0
+        }
+    }
+
+    /// true for a penalty that must be broken at
+    pub fn is_forced(&self) -> bool {
+        matches!(self, BreakItem::Penalty { penalty, .. } if *penalty == f32::NEG_INFINITY)
+    }
+
+    /// true for a penalty that ends in a hyphen
+    pub fn is_flagged(&self) -> bool {
+        matches!(self, BreakItem::Penalty { flagged: true, .. })
+    }
+
+    /// true when a line may end at `items[index]`: glue following a box, or any penalty that isn't forbidden
+    pub fn is_legal_break(items: &[BreakItem], index: usize) -> bool {
+        match items[index] {
+            BreakItem::Glue { .. } => index > 0 && matches!(items[index - 1], BreakItem::Box { .. }),
+            BreakItem::Penalty { penalty, .. } => penalty < f32::INFINITY,
+            BreakItem::Box { .. } => false
+        }
+    }
+
+    /// index of the first item of a line following a break at `index - 1`, glue and optional penalties there are discarded
+    pub fn line_start(items: &[BreakItem], mut index: usize) -> usize {
+        while let Some(item) = items.get(index) {
+            match item {
+                BreakItem::Box { .. } => break,
+                BreakItem::Penalty { .. } if item.is_forced() => break,
+                _ => index += 1
+            }
+        }
+
+        index
+    }
+
+    /// running totals of width, finite stretch, shrink and infinite stretches in front of each item, plus the end
+    pub fn totals(items: &[BreakItem]) -> Vec<Totals> {
+        let mut totals: Vec<Totals> = Vec::with_capacity(items.len() + 1);
+        let mut total = Totals::default();
+
+        totals.push(total);
+
+        for item in items {
+            match item {
+                BreakItem::Box { width, .. } => total.width += *width as f64,
+                BreakItem::Glue { width, stretch, shrink, .. } => {
+                    total.width += *width as f64;
+                    total.shrink += *shrink as f64;
+
+                    match stretch.is_infinite() {
+                        true => total.fills += 1,
+                        false => total.stretch += *stretch as f64
+                    }
+                },
+                BreakItem::Penalty { .. } => {}
+            }
+
+            totals.push(total);
+        }
+
+        totals
+    }
+}
+
+/// sums of the items in front of a position, subtracting two gives the natural width and flexibility of a line
+#[derive(Debug,Clone,Copy,Default,PartialEq)]
+pub struct Totals {
+    pub width: f64,
+    pub stretch: f64,
+    pub shrink: f64,
+    // glue that stretches without limit, counted rather than summed
+    pub fills: u32,
+}
+
+// items built by the tests of the line breakers
+#[cfg(test)]
+pub(crate) mod test_items {
+    use crate::types::{ BreakItem, TextStyle, Word };
+
+    pub(crate) fn word(text: &'static str, width: f32) -> Word<'static> {
+        Word { text_style: TextStyle::new(12.0), offset: 0.0, text, space: "", width }
+    }
+
+    pub(crate) fn boxed(width: f32) -> BreakItem<'static> {
+        BreakItem::Box { words: vec![word("x", width)], width }
+    }
+
+    pub(crate) fn glue(width: f32, stretch: f32, shrink: f32) -> BreakItem<'static> {
+        BreakItem::Glue { text: " ", width, stretch, shrink }
+    }
+
+    pub(crate) fn hyphen_penalty(width: f32) -> BreakItem<'static> {
+        BreakItem::Penalty { penalty: 50.0, flagged: true, hyphen: Some(word("-", width)) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::BreakItem;
+    use super::test_items::{ boxed, glue, hyphen_penalty };
+
+    #[test]
+    fn forced_break_ends_in_a_forced_penalty_after_infinite_glue() {
+        let items = BreakItem::forced_break();
+
+        assert!(!items[0].is_forced());
+        assert!(matches!(items[1], BreakItem::Glue { stretch, .. } if stretch == f32::INFINITY));
+        assert!(items[2].is_forced());
+    }
+
+    #[test]
+    fn flagged_penalties_count_their_hyphen_in_the_break_width() {
+        let penalty = hyphen_penalty(4.0);
+
+        assert!(penalty.is_flagged());
+        assert_eq!(penalty.break_width(), 4.0);
+        assert_eq!(boxed(10.0).break_width(), 0.0);
+        assert_eq!(glue(10.0, 5.0, 3.0).break_width(), 0.0);
+    }
+
+    #[test]
+    fn glue_is_a_legal_break_only_after_a_box() {
+        let items = vec![glue(5.0, 2.5, 1.5), boxed(10.0), glue(5.0, 2.5, 1.5), glue(5.0, 2.5, 1.5)];
+
+        assert!(!BreakItem::is_legal_break(&items, 0));
+        assert!(BreakItem::is_legal_break(&items, 2));
+        assert!(!BreakItem::is_legal_break(&items, 3));
+    }
+
+    #[test]
+    fn forbidden_penalties_are_not_legal_breaks() {
+        let mut items = vec![boxed(10.0), hyphen_penalty(4.0)];
+        items.extend(BreakItem::forced_break());
+
+        assert!(BreakItem::is_legal_break(&items, 1));
+        assert!(!BreakItem::is_legal_break(&items, 2));
+        assert!(BreakItem::is_legal_break(&items, 4));
+        assert!(!BreakItem::is_legal_break(&items, 0));
+    }
+
+    #[test]
+    fn line_start_skips_glue_and_optional_penalties() {
+        let items = vec![glue(5.0, 2.5, 1.5), hyphen_penalty(4.0), boxed(10.0)];
+
+        assert_eq!(BreakItem::line_start(&items, 0), 2);
+        assert_eq!(BreakItem::line_start(&items, 3), 3);
+    }
+
+    #[test]
+    fn line_start_stops_at_a_forced_break() {
+        // an empty line between two hard breaks starts at the second one
+        let mut items = vec![glue(5.0, 2.5, 1.5)];
+        items.extend(BreakItem::forced_break());
+
+        assert_eq!(BreakItem::line_start(&items, 0), 3);
+    }
+
+    #[test]
+    fn totals_sum_the_items_in_front_of_each_position() {
+        let mut items = vec![boxed(10.0), glue(6.0, 3.0, 2.0), boxed(20.0)];
+        items.extend(BreakItem::forced_break());
+
+        let totals = BreakItem::totals(&items);
+
+        assert_eq!(totals.len(), items.len() + 1);
+        assert_eq!(totals[0].width, 0.0);
+        assert_eq!(totals[2].width, 16.0);
+        assert_eq!(totals[2].stretch, 3.0);
+        assert_eq!(totals[2].shrink, 2.0);
+        assert_eq!(totals[3].width, 36.0);
+        // infinite stretch is counted, never summed
+        assert_eq!(totals[6].stretch, 3.0);
+        assert_eq!(totals[6].fills, 1);
+    }
+}
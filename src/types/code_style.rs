@@ -0,0 +1,138 @@
+use crate::types::{ Color, FontFamily, HighlightTheme };
+
+/// What happens to a line of a code block wider than the block
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Default)]
+pub enum CodeOverflow {
+    /// the rest of the line continues on the next line, the wrapped line ends in `CodeStyle.continuation`
+    #[default]
+    Wrap,
+    /// the rest of the line is cut off at the right edge of the block
+    Clip,
+}
+
+/// Code block and inline code settings shared by every `codeBlock` and `code` mark in a `Doc`
+///
+/// # Example
+/// ```
+/// # use edra::types::{ CodeOverflow, CodeStyle, Color, FontFamily };
+/// let code_style = CodeStyle::new()
+///     .with_family(FontFamily::Courier)                     // FontFamily, a monospaced family
+///     .and_font_size(9.0)                                   // f32, size of code blocks
+///     .and_background(Some(Color::new(40, 44, 52)))         // Option<Color>, behind code blocks
+///     .and_inline_background(None)                          // Option<Color>, behind `code` marks
+///     .and_padding(8.0)                                     // f32
+///     .and_tab_width(2)                                     // usize, columns between tab stops
+///     .and_overflow(CodeOverflow::Clip)                     // CodeOverflow
+///     .and_continuation("\u{bb}");                          // &'static str, ends wrapped lines
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct CodeStyle {
+    /// family of code blocks and `code` marks, an embedded font is only monospaced if the embedded font is
+    pub family: FontFamily,
+    pub font_size: f32,
+    /// box painted behind the lines of a code block, none when `None`
+    pub background: Option<Color>,
+    /// highlight painted behind `code` marks unless they are highlighted already
+    pub inline_background: Option<Color>,
+    /// space between the box of a code block and its text, and above and below the block
+    pub padding: f32,
+    pub tab_width: usize,
+    pub overflow: CodeOverflow,
+    /// marker drawn at the end of a line wrapped by `CodeOverflow::Wrap`
+    pub continuation: &'static str,
+    /// colours of the tokens of code blocks in a known `language`, code is left uncoloured when `None`
+    /// - only applied with the `highlight` feature, the field exists either way so enabling it never breaks a build
+    pub theme: Option<HighlightTheme>,
+}
+
+impl CodeStyle {
+    /// default settings:
+    /// - Family: Courier
+    /// - Font size: 10.0
+    /// - Background: light grey behind blocks and inline code
+    /// - Padding: 6.0
+    /// - Tab width: 4
+    /// - Long lines wrapped, ending in `»`
+    /// - Theme: the default `HighlightTheme` (`highlight` feature)
+    pub fn new() -> Self {
+        CodeStyle::default()
+    }
+
+    /// builder function setting the family code is set in
+    pub fn with_family(mut self, family: FontFamily) -> Self {
+        self.family = family;
+        self
+    }
+
+    /// builder function setting the font size of code blocks
+    pub fn and_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// builder function setting the background of code blocks
+    pub fn and_background(mut self, background: Option<Color>) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// builder function setting the background of inline code
+    pub fn and_inline_background(mut self, inline_background: Option<Color>) -> Self {
+        self.inline_background = inline_background;
+        self
+    }
+
+    /// builder function setting the padding of code blocks
+    pub fn and_padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// builder function setting the number of columns between tab stops
+    pub fn and_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// builder function setting whether long lines are wrapped or clipped
+    pub fn and_overflow(mut self, overflow: CodeOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// builder function setting the marker ending wrapped lines
+    pub fn and_continuation(mut self, continuation: &'static str) -> Self {
+        self.continuation = continuation;
+        self
+    }
+
+    /// builder function setting the colours of highlighted tokens, used by the `highlight` feature
+    /// ```
+    /// # use edra::types::{ CodeStyle, HighlightTheme };
+    /// // the same code builds with and without the feature
+    /// let code_style = CodeStyle { theme: None, ..CodeStyle::new() }
+    ///     .and_theme(Some(HighlightTheme::new().and_plain(None)));
+    ///
+    /// assert!(code_style.theme.is_some());
+    /// ```
+    pub fn and_theme(mut self, theme: Option<HighlightTheme>) -> Self {
+        self.theme = theme;
+        self
+    }
+}
+
+impl Default for CodeStyle {
+    fn default() -> Self {
+        CodeStyle {
+            family: FontFamily::Courier,
+            font_size: 10.0,
+            background: Some(Color::new(244, 244, 244)),
+            inline_background: Some(Color::new(236, 236, 236)),
+            padding: 6.0,
+            tab_width: 4,
+            overflow: CodeOverflow::Wrap,
+            continuation: "\u{bb}",
+            theme: Some(HighlightTheme::default()),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use crate::types::{AttributeField, FontStyle};
 use serde::Deserialize;
 
 /// Deserialized from `type`field
-#[derive(Debug,Deserialize,PartialEq)]
+#[derive(Debug,Deserialize,PartialEq,Eq,Hash,Clone,Copy)]
 pub enum BlockType {
    #[serde(rename = "paragraph")]
     Paragraph,
@@ -12,10 +12,18 @@ pub enum BlockType {
     Break,
    #[serde(rename = "orderedList")]
     OrderedList,
+   #[serde(rename = "bulletList")]
+    BulletList,
    #[serde(rename = "text")]
     Text,
    #[serde(rename = "listItem")]
     ListItem,
+   #[serde(rename = "blockquote")]
+    Blockquote,
+   #[serde(rename = "codeBlock")]
+    CodeBlock,
+   #[serde(rename = "horizontalRule")]
+    HorizontalRule,
 }
 
 /// Deserialized from `content` field
@@ -1,22 +1,60 @@
+use std::collections::{ BTreeSet, HashMap };
+use std::fmt::Write;
+use chrono::Local;
 use serde::Deserialize;
-use pdf_writer::{Chunk, Content, Finish, Name, Pdf, Rect, TextStr };
+use pdf_writer::{Chunk, Finish, Name, Pdf, Rect, Ref, Str, TextStr };
+use pdf_writer::types::{ ActionType, AnnotationType, CidFontType, FontFlags, SystemInfo, UnicodeCmap };
+use unicode_linebreak::{ linebreaks, BreakOpportunity };
 use crate::{
-    traits::FontType, 
+    traits::FontType,
     types::{ 
-        AttributeField, 
         BlockType, 
+        BreakItem,
+        CodeOverflow,
+        CodeStyle,
         ContentField, 
+        EmbeddedFont,
         Font, 
+        FontEncoding,
         FontFamily,
         FontReference,
-        Page,
-        PageContent,
-        Style, 
+        FontSlant,
+        FontWeight,
+        FontVariants,
+        HeaderFooter,
+        HeadingStyle,
+        HeadingStyles,
+        Hyphenation,
+        Justification,
+        JustifySpacing,
+        Label,
+        Line,
+        Nesting,
+        LineBreaking,
+        LinkStyle,
+        ListStyle,
+        PageSetup,
+        Pagination,
+        QuoteRule,
+        QuoteStyle,
+        RegisteredFont,
+        RenderError,
+        RuleStyle,
         TextAlignment,
         TextBlock,
+        TextStyle,
+        TrueTypeFont,
         Word,
-        Writer
+        Writer,
+        decompose_ligature,
+        win_ansi_char,
+        win_ansi_substitute
  }};
+#[cfg(feature = "highlight")]
+use crate::types::Language;
+
+// breaking whitespace is drawn as plain spaces, sliced from here
+const SPACES: &str = "                                ";
 
 /// # Main entry point of the library
 #[derive(Debug,Deserialize)]
@@ -26,135 +64,482 @@ pub struct Doc {
     pub doc_type: Option<String>,
     /// Deserialized from JSON `content` field
     pub content: Vec<ContentField>,
+    /// Not part of the JSON input: paper size, orientation and margins used for every page
+   #[serde(skip)]
+    pub page_setup: PageSetup,
+    /// Not part of the JSON input: bullet characters and indentation used for lists
+   #[serde(skip)]
+    pub list_style: ListStyle,
+    /// Not part of the JSON input: TrueType/OpenType family used for all text instead of Times Roman
+   #[serde(skip)]
+    pub embedded_font: Option<EmbeddedFont>,
+    /// Not part of the JSON input: family used for all text, defaults to the embedded font when one is set, Times Roman otherwise
+   #[serde(skip)]
+    pub font_family: Option<FontFamily>,
+    /// Not part of the JSON input: families overriding `font_family` for every block of a given type
+   #[serde(skip)]
+    pub block_families: HashMap<BlockType, FontFamily>,
+    /// Not part of the JSON input: families registered with `Doc::register_font()`
+   #[serde(skip)]
+    pub registered_fonts: Vec<RegisteredFont>,
+    /// Not part of the JSON input: colour and underline of link text
+   #[serde(skip)]
+    pub link_style: LinkStyle,
+    /// Not part of the JSON input: greedy or total fit (Knuth & Plass) line breaking
+   #[serde(skip)]
+    pub line_breaking: LineBreaking,
+    /// Not part of the JSON input: language words are hyphenated in, no hyphenation when `None`
+   #[serde(skip)]
+    pub hyphenation: Option<Hyphenation>,
+    /// Not part of the JSON input: last line alignment and spacing of justified text
+   #[serde(skip)]
+    pub justification: Justification,
+    /// Not part of the JSON input: widow, orphan and keep rules deciding where blocks are split across pages
+   #[serde(skip)]
+    pub pagination: Pagination,
+    /// Not part of the JSON input: text repeated at the top of every page
+   #[serde(skip)]
+    pub header: Option<HeaderFooter>,
+    /// Not part of the JSON input: text repeated at the bottom of every page
+   #[serde(skip)]
+    pub footer: Option<HeaderFooter>,
+    /// Not part of the JSON input: document title, written wherever a header or footer has a `{title}` variable
+   #[serde(skip)]
+    pub title: Option<String>,
+    /// Not part of the JSON input: size, weight, family, colour and spacing of each heading level
+   #[serde(skip)]
+    pub heading_styles: HeadingStyles,
+    /// Not part of the JSON input: indent, rule and slant of blockquotes
+   #[serde(skip)]
+    pub quote_style: QuoteStyle,
+    /// Not part of the JSON input: family, background and overflow of code blocks and inline code
+   #[serde(skip)]
+    pub code_style: CodeStyle,
+    /// Not part of the JSON input: thickness, colour, line style and spacing of horizontal rules
+   #[serde(skip)]
+    pub rule_style: RuleStyle,
 }
 
 impl Doc {
 
-    /// applies an offset to each line of text based on the JSON `textAlign` field
-    fn apply_text_alignment(text_block: &mut TextBlock, writeable_area: f32) {
+    /// builder function setting the paper size, orientation and margins of the rendered PDF
+    /// ```
+    /// # use edra::types::{ Doc, PageSetup, PaperSize };
+    /// let doc = Doc::default()
+    ///     .with_page_setup(PageSetup::new().with_paper_size(PaperSize::Letter));
+    /// ```
+    pub fn with_page_setup(mut self, page_setup: PageSetup) -> Self {
+        self.page_setup = page_setup;
+        self
+    }
 
-        match text_block.alignment {
-            TextAlignment::Left => {},
-            TextAlignment::Center => {
-                for line in &mut text_block.lines {
-                    line.offset = Doc::offset_center(line.width, writeable_area);
-                }
-            },
-            TextAlignment::Right => {
-                for line in &mut text_block.lines {
-                    line.offset = Doc::offset_right_justify(line.width, writeable_area);
-                }
-            },
-            TextAlignment::Justify => {
-                let list_length = text_block.lines.len();
-
-                if list_length > 2 {
-                    for index in 0..(list_length -1) {
-                        let line = {
-                            match text_block.lines.get_mut(index) {
-                                Some(line) => line,
-                                None => { return }
-                            }
-                        };
+    /// builder function embedding a TrueType/OpenType font family, used for every weight and slant in place of Times Roman
+    /// - glyph widths are read from the font's `hmtx` table
+    /// - the font is embedded as a subsetted Type0 font with a ToUnicode CMap
+    pub fn with_embedded_font(mut self, embedded_font: EmbeddedFont) -> Self {
+        self.embedded_font = Some(embedded_font);
+        self
+    }
 
-                        if line.body.len() > 2 {
-                            let offset = (writeable_area - line.width) / (line.body.len()-1) as f32;
+    /// builder function setting the font family used for all text
+    /// ```
+    /// # use edra::types::{ Doc, FontFamily };
+    /// let doc = Doc::default()
+    ///     .with_font_family(FontFamily::Helvetica);
+    /// ```
+    pub fn with_font_family(mut self, font_family: FontFamily) -> Self {
+        self.font_family = Some(font_family);
+        self
+    }
 
-                            for word in &mut line.body {
-                                word.offset += offset;
-                            }
-                        }
-                    }
-                }
-            }
-        }
+    /// builder function setting the font family used for every block of `block_type`, overriding `with_font_family()`
+    /// - list items are rendered with the family of their list
+    /// ```
+    /// # use edra::types::{ BlockType, Doc, FontFamily };
+    /// let doc = Doc::default()
+    ///     .with_font_family(FontFamily::TimesRoman)
+    ///     .with_block_font_family(BlockType::Heading, FontFamily::Helvetica);
+    /// ```
+    pub fn with_block_font_family(mut self, block_type: BlockType, font_family: FontFamily) -> Self {
+        self.block_families.insert(block_type, font_family);
+        self
     }
 
-    /// helper function for lists, returns the first text node in a content block
-    fn find_first_text_node_mut (list: &mut [ContentField]) -> Option<&mut String> {
-        for node in list {
-            if let Some(text) = node.text.as_mut() {
-                return Some(text);
-            }
-            if let Some(children) = node.content.as_mut() {
-                if let Some(text) = Doc::find_first_text_node_mut(children) {
-                    return Some(text);
-                }
-            }
-        }
+    /// registers a font family under `name`, selected with `FontFamily::Registered(name)`
+    /// - `font` measures every character, line breaking and the PDF `Widths` arrays both rely on it
+    /// - `variants` names the fonts drawing each style, missing variants fall back to the normal variant
+    /// - registering a name twice replaces the earlier family
+    /// - `render()` rejects names that are, or start with, the name of another family and a `-` (`times-bold`)
+    ///
+    /// The fonts are written as WinAnsiEncoding Type1 fonts referenced by name and are not embedded.
+    /// ```
+    /// # use edra::traits::FontType;
+    /// # use edra::types::{ Doc, FontFamily, FontVariants, RenderError };
+    /// # struct Mono;
+    /// # impl FontType for Mono {
+    /// #     fn new() -> Self { Mono }
+    /// #     fn normal(&self, _ch: &char, font_size: f32) -> f32 { font_size * 0.6 }
+    /// #     fn bold(&self, _ch: &char, font_size: f32) -> f32 { font_size * 0.6 }
+    /// #     fn italic(&self, _ch: &char, font_size: f32) -> f32 { font_size * 0.6 }
+    /// #     fn bold_italic(&self, _ch: &char, font_size: f32) -> f32 { font_size * 0.6 }
+    /// #     fn standardize(width: f32, font_size: f32) -> f32 { width * font_size / 1000.0 }
+    /// # }
+    /// // its italic variant would be labelled like Times BoldItalic
+    /// let mut doc = Doc::default()
+    ///     .register_font("times-bold", Mono, FontVariants::new("Mono").and_italic("Mono-Italic"))
+    ///     .with_font_family(FontFamily::Registered("times-bold"));
+    ///
+    /// assert!(matches!(doc.render(), Err(RenderError::InvalidFont(_))));
+    /// ```
+    pub fn register_font(mut self, name: &'static str, font: impl FontType + Send + Sync + 'static, variants: FontVariants) -> Self {
+        self.registered_fonts.retain(|registered| registered.name != name);
+        self.registered_fonts.push(RegisteredFont { name, widths: Box::new(font), variants });
+        self
+    }
 
-        None
+    /// builder function setting the colour and underline of link text
+    /// - every line a link is broken across gets a `/Link` annotation of its own, covering the link text on that line
+    /// ```
+    /// # use edra::types::{ Doc, LinkStyle };
+    /// let mut doc = serde_json::from_str::<Doc>(r#"{ "type": "doc", "content": [
+    ///     { "type": "paragraph", "content": [
+    ///         { "type": "text", "text": "Read more in the " },
+    ///         { "type": "text", "marks": [{ "type": "link", "attrs": { "href": "https://example.com/report" } }], "text": "annual report of the committee on typographical conventions and extraordinarily long link texts that wrap" },
+    ///         { "type": "text", "text": " today." }
+    ///     ] }
+    /// ] }"#)
+    ///     .expect("invalid json")
+    ///     .with_link_style(LinkStyle::new());
+    ///
+    /// let pdf_file = doc.render().expect("render failed");
+    /// let content = String::from_utf8_lossy(&pdf_file);
+    /// let tops: Vec<&str> = content
+    ///     .split("/Subtype /Link")
+    ///     .skip(1)
+    ///     .filter_map(|annotation| annotation.split("/Rect [").nth(1)?.split(' ').nth(3))
+    ///     .collect();
+    ///
+    /// // the link wraps onto a second line, each line is annotated with the link's target
+    /// assert_eq!(content.matches("/URI (https://example.com/report)").count(), 2);
+    /// assert_eq!(tops.len(), 2);
+    /// assert_ne!(tops[0], tops[1]);
+    /// ```
+    pub fn with_link_style(mut self, link_style: LinkStyle) -> Self {
+        self.link_style = link_style;
+        self
     }
-    
-    /// returns the base font `Style` for a text block
-    fn get_block_font_style(block: &ContentField) -> Style {
-        let mut current_style = Style::Normal;
 
-        if let Some(styles) = &block.style {
-            let mut style_list: Vec<Style> = Vec::new();
+    /// builder function setting how text blocks are broken into lines
+    /// ```
+    /// # use edra::types::{ Doc, LineBreaking, TotalFit };
+    /// let doc = Doc::default()
+    ///     .with_line_breaking(LineBreaking::TotalFit(TotalFit::new()));
+    /// ```
+    pub fn with_line_breaking(mut self, line_breaking: LineBreaking) -> Self {
+        self.line_breaking = line_breaking;
+        self
+    }
 
-            for style_opt in styles {
-                if let Some(name) = style_opt.name() {
-                    style_list.push(name);
-                }
-            }
+    /// builder function hyphenating words with the patterns of a language
+    /// ```
+    /// # use edra::types::{ Doc, Hyphenation };
+    /// let doc = Doc::default()
+    ///     .with_hyphenation(Hyphenation::english());
+    /// ```
+    pub fn with_hyphenation(mut self, hyphenation: Hyphenation) -> Self {
+        self.hyphenation = Some(hyphenation);
+        self
+    }
 
-            // compiled styles defined
-            current_style = match style_list.contains(&Style::Bold) & style_list.contains(&Style::Italic) {
-                true => Style::BoldItalic,
-                false => {
-                    if style_list.contains(&Style::Bold) {
-                         Style::Bold
-                    } else if style_list.contains(&Style::Italic) { 
-                        Style::Italic
-                    } else {
-                        current_style
-                    }
-                }
+    /// builder function setting how justified text blocks are stretched and how their last line aligns
+    /// ```
+    /// # use edra::types::{ Doc, Justification, JustifySpacing };
+    /// let doc = Doc::default()
+    ///     .with_justification(Justification::new().and_spacing(JustifySpacing::Operators));
+    /// ```
+    pub fn with_justification(mut self, justification: Justification) -> Self {
+        self.justification = justification;
+        self
+    }
+
+    /// builder function setting the widow, orphan and keep rules used to split blocks across pages
+    /// ```
+    /// # use edra::types::{ BlockType, Doc, Pagination };
+    /// let doc = Doc::default()
+    ///     .with_pagination(Pagination::new().with_orphans(3).and_keep_together(BlockType::ListItem));
+    /// ```
+    pub fn with_pagination(mut self, pagination: Pagination) -> Self {
+        self.pagination = pagination;
+        self
+    }
+
+    /// builder function setting the header repeated at the top of every page
+    /// - `render()` fails with `RenderError::InvalidDateFormat` when chrono can't parse the date format
+    /// ```
+    /// # use edra::types::{ Doc, HeaderFooter, RenderError, Slots };
+    /// let doc = Doc::default()
+    ///     .with_header(HeaderFooter::new(Slots::new().with_left("{title}").and_right("{date}")));
+    ///
+    /// let mut doc = Doc::default()
+    ///     .with_header(HeaderFooter::new(Slots::new().and_right("{date}")).and_date_format("%Q"));
+    ///
+    /// assert!(matches!(doc.render(), Err(RenderError::InvalidDateFormat(_))));
+    /// ```
+    pub fn with_header(mut self, header: HeaderFooter) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    /// builder function setting the footer repeated at the bottom of every page
+    /// ```
+    /// # use edra::types::{ Doc, HeaderFooter, Slots };
+    /// let doc = Doc::default()
+    ///     .with_footer(HeaderFooter::new(Slots::new().and_center("Page {page} of {pages}")));
+    /// ```
+    pub fn with_footer(mut self, footer: HeaderFooter) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
+    /// builder function setting the document title used by the `{title}` variable of headers and footers
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// builder function setting the style of a heading level from 1 to 6
+    /// ```
+    /// # use edra::types::{ Doc, FontFamily, HeadingStyle };
+    /// let doc = Doc::default()
+    ///     .with_heading_style(1, HeadingStyle::new(30.0).and_family(Some(FontFamily::Helvetica)));
+    /// ```
+    pub fn with_heading_style(mut self, level: u8, heading_style: HeadingStyle) -> Self {
+        self.heading_styles = self.heading_styles.with_level(level, heading_style);
+        self
+    }
+
+    /// builder function setting the indent, rule and slant of blockquotes
+    /// - the rule of a quote split across pages is drawn down its lines on each page
+    /// ```
+    /// # use edra::types::{ Color, Doc, QuoteStyle };
+    /// let doc = Doc::default()
+    ///     .with_quote_style(QuoteStyle::new().and_rule_color(Color::new(0, 102, 204)).and_italic(true));
+    ///
+    /// // a quote long enough to fill two pages
+    /// let paragraph = r#"{ "type": "paragraph", "content": [{ "type": "text", "text": "Quoted at length." }] }"#;
+    /// let json = format!(r#"{{ "type": "doc", "content": [{{ "type": "blockquote", "content": [{}] }}] }}"#, vec![paragraph; 60].join(","));
+    /// let mut doc = serde_json::from_str::<Doc>(&json)
+    ///     .expect("invalid json")
+    ///     .with_quote_style(QuoteStyle::new());
+    ///
+    /// let pdf_file = doc.render().expect("render failed");
+    /// let content = String::from_utf8_lossy(&pdf_file);
+    /// // a vertical line: moved to and drawn to the same x
+    /// let has_rule = |page: &str| {
+    ///     let lines: Vec<&str> = page.lines().collect();
+    ///
+    ///     lines.windows(2).any(|window| match (window[0].strip_suffix(" m"), window[1].strip_suffix(" l")) {
+    ///         (Some(from), Some(to)) => from.split(' ').next() == to.split(' ').next(),
+    ///         _ => false
+    ///     })
+    /// };
+    /// let pages: Vec<&str> = content.split("endstream").filter(|page| page.contains("(Quoted) Tj")).collect();
+    ///
+    /// assert_eq!(pages.len(), 2);
+    /// assert!(pages.iter().all(|page| has_rule(page)));
+    /// ```
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// builder function setting the family, background and overflow of code blocks and inline code
+    /// ```
+    /// # use edra::types::{ CodeOverflow, CodeStyle, Doc };
+    /// let doc = Doc::default()
+    ///     .with_code_style(CodeStyle::new().and_font_size(9.0).and_overflow(CodeOverflow::Clip));
+    /// ```
+    pub fn with_code_style(mut self, code_style: CodeStyle) -> Self {
+        self.code_style = code_style;
+        self
+    }
+
+    /// builder function setting the thickness, colour, line style and spacing of horizontal rules
+    /// - a rule without room above the bottom margin moves to the top of the next page
+    /// ```
+    /// # use edra::types::{ Doc, LineStyle, RuleStyle };
+    /// let doc = Doc::default()
+    ///     .with_rule_style(RuleStyle::new().with_thickness(0.5).and_line_style(LineStyle::Dotted));
+    ///
+    /// // a page of short paragraphs leaves less than the space above the rule at the bottom
+    /// let paragraph = r#"{ "type": "paragraph", "content": [{ "type": "text", "text": "Filler" }] }"#;
+    /// let mut blocks = vec![paragraph; 41];
+    ///
+    /// blocks.extend([r#"{ "type": "horizontalRule" }"#, paragraph]);
+    ///
+    /// let mut doc = serde_json::from_str::<Doc>(&format!(r#"{{ "type": "doc", "content": [{}] }}"#, blocks.join(",")))
+    ///     .expect("invalid json")
+    ///     .with_rule_style(RuleStyle::new());
+    ///
+    /// let pdf_file = doc.render().expect("render failed");
+    /// let content = String::from_utf8_lossy(&pdf_file);
+    /// let pages: Vec<&str> = content.split("endstream").filter(|page| page.contains("(Filler) Tj")).collect();
+    /// let y = |line: &str| line.split(' ').nth(1).and_then(|y| y.parse::<f32>().ok());
+    /// let rule = pages.iter().position(|page| page.lines().any(|line| line.ends_with(" m")));
+    ///
+    /// assert_eq!(pages.len(), 2);
+    /// assert_eq!(rule, Some(1));
+    ///
+    /// // on the second page the rule is drawn above the paragraph following it
+    /// let rule_y = pages[1].lines().find(|line| line.ends_with(" m")).and_then(y);
+    /// let text_y = pages[1].lines().find(|line| line.ends_with(" Td")).and_then(y);
+    ///
+    /// assert!(rule_y > text_y);
+    /// ```
+    pub fn with_rule_style(mut self, rule_style: RuleStyle) -> Self {
+        self.rule_style = rule_style;
+        self
+    }
+
+    /// builder function setting the bullet characters and indentation of lists
+    /// - a list item is numbered on its first line whatever its first block is, a nested list included
+    /// ```
+    /// # use edra::types::{ Doc, ListStyle };
+    /// let mut doc = serde_json::from_str::<Doc>(r#"{ "type": "doc", "content": [
+    ///     { "type": "orderedList", "content": [
+    ///         { "type": "listItem", "content": [
+    ///             { "type": "bulletList", "content": [
+    ///                 { "type": "listItem", "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "Inner" }] }] }
+    ///             ] }
+    ///         ] }
+    ///     ] }
+    /// ] }"#)
+    ///     .expect("invalid json")
+    ///     .with_list_style(ListStyle::new().with_bullets(vec!['-']));
+    ///
+    /// let pdf_file = doc.render().expect("render failed");
+    /// let content = String::from_utf8_lossy(&pdf_file);
+    /// let lines: Vec<&str> = content.lines().collect();
+    /// let baseline = |text: &str| lines
+    ///     .windows(3)
+    ///     .find(|window| window[2] == text)
+    ///     .and_then(|window| window[0].split(' ').nth(1));
+    ///
+    /// // the outer number sits on the line of the inner bullet, in front of it
+    /// assert!(baseline("(1.) Tj").is_some());
+    /// assert_eq!(baseline("(1.) Tj"), baseline("(-) Tj"));
+    /// ```
+    pub fn with_list_style(mut self, list_style: ListStyle) -> Self {
+        self.list_style = list_style;
+        self
+    }
+
+    /// applies an offset to each line of text based on the JSON `textAlign` field
+    /// - justified blocks stretch every line but the last, which is aligned by `Justification.last_line`
+    /// - lines ended by a hard break are aligned like the last line
+    fn apply_text_alignment(text_block: &mut TextBlock, writeable_area: f32, justification: &Justification) {
+        let last = text_block.lines.len().saturating_sub(1);
+        // `Tw` only widens single byte spaces, embedded fonts are drawn with two byte codes
+        let word_spacing = text_block.font_family != FontFamily::Embedded;
+
+        for (index, line) in text_block.lines.iter_mut().enumerate() {
+            let alignment = match text_block.alignment {
+                TextAlignment::Justify if index == last || line.hard_break => justification.last_line,
+                alignment => alignment
             };
 
-            if style_list.contains(&Style::Underline) {
-                current_style =match current_style {
-                    Style::Bold => Style::BoldUnderline,
-                    Style::Italic => Style::ItalicUnderline,
-                    Style::Normal => Style::Underline,
-                    Style::BoldItalic => Style::BoldItalicUnderline,
-                    _ => current_style
-                }
+            match alignment {
+                TextAlignment::Left => {},
+                TextAlignment::Center => line.offset = Doc::offset_center(line.width, writeable_area),
+                TextAlignment::Right => line.offset = Doc::offset_right_justify(line.width, writeable_area),
+                TextAlignment::Justify => Doc::justify_line(line, writeable_area, justification, word_spacing)
             }
+        }
+    }
 
-            if style_list.contains(&Style::Strikethrough) {
-                current_style =match current_style {
-                    Style::Bold => Style::BoldStrikethrough,
-                    Style::Italic => Style::ItalicStrikethrough,
-                    Style::Normal => Style::BoldItalicStrikethrough,
-                    Style::BoldItalic => Style::Strikethrough,
-                    _ => current_style
-                }
+    /// stretches (or shrinks) a line to the writeable width
+    /// - positioned words: the gaps between segments are widened, words joined across marks stay together
+    /// - operators: the spaces are widened with `Tw`, or every character with `Tc` when `Tw` can't be used
+    /// - lines without gaps fall back to letter spacing, up to `Justification.max_letter_spacing`
+    /// - letter spacing only stretches, overfull lines are left at their natural width rather than overlap
+    fn justify_line(line: &mut Line, writeable_area: f32, justification: &Justification, word_spacing: bool) {
+        let extra = writeable_area - line.width;
+        let last = line.body.len().saturating_sub(1);
+        let gaps = line.body[..last].iter().filter(|word| word.offset > 0.0).count();
+        let operators = justification.spacing == JustifySpacing::Operators;
+
+        if line.body.is_empty() || extra == 0.0 {
+            return;
+        }
+
+        if gaps > 0 && !operators {
+            let offset = extra / gaps as f32;
+
+            for word in line.body[..last].iter_mut().filter(|word| word.offset > 0.0) {
+                word.offset += offset;
             }
 
+            return;
         }
 
-        current_style
-    }
+        let spaces = |text: &str| text.chars().filter(|ch| *ch == ' ').count() as f32;
+        let space_count: f32 = line.body.iter().map(|word| spaces(word.text) + spaces(word.space)).sum();
 
-    /// returns nested `AattributeField` for a block or section
-    fn get_block_attributes(block: &ContentField) -> Option<&AttributeField> {
-        if let Some(style_list) = &block.style {
-            let list_length = style_list.len();
+        if gaps > 0 && word_spacing && space_count > 0.0 {
+            line.word_spacing = extra / space_count;
 
-            for index in 0..list_length {
-                if let Some(style) = style_list.get(index) {
-                    if let Some(attribute) = &style.attributes {
-                        return Some(attribute);
-                    }
-                } else {
-                    continue
-                }
+            for word in line.body.iter_mut() {
+                word.width += line.word_spacing * spaces(word.text);
+                word.offset += line.word_spacing * spaces(word.space);
             }
+
+            return;
+        }
+
+        // spaces are only drawn, and so only letter spaced, when lines are written as continuous text
+        let characters = |word: &Word| match operators {
+            true => (word.text.chars().count() as f32, word.space.chars().count() as f32),
+            false => (word.text.chars().count() as f32, 0.0)
+        };
+        // the spacing after the last character on the line is never seen
+        let character_count: f32 = line.body.iter().map(|word| characters(word).0 + characters(word).1).sum::<f32>() - 1.0;
+        let char_spacing = extra / character_count.max(1.0);
+
+        if extra < 0.0 || char_spacing > justification.max_letter_spacing * line.font_size {
+            return;
+        }
+
+        line.char_spacing = char_spacing;
+
+        for word in line.body.iter_mut() {
+            let (text, space) = characters(word);
+
+            word.width += char_spacing * text;
+            word.offset += char_spacing * space;
+        }
+    }
+
+    /// folds the marks of a text run into its `TextStyle`, sizes are relative to the block's font size
+    /// - link text takes the `LinkStyle` colour unless the run has a colour of its own
+    /// - inline code is set in the `CodeStyle` family, on its background unless the run is highlighted
+    fn get_text_style<'a>(section: &'a ContentField, block_style: &TextStyle<'static>, link_style: &LinkStyle, code_style: &CodeStyle) -> TextStyle<'a> {
+        let mut text_style = *block_style;
+
+        for mark in section.style.iter().flatten() {
+            text_style.apply(mark);
         }
 
-        None
+        if text_style.link.is_some() {
+            text_style.color = text_style.color.or(link_style.color);
+            text_style.underline |= link_style.underline;
+        }
+
+        if text_style.code {
+            text_style.family = Some(code_style.family);
+            text_style.highlight = text_style.highlight.or(code_style.inline_background);
+        }
+
+        text_style
     }
 
     fn get_block_text_alignment(block: &ContentField) -> TextAlignment {
@@ -172,19 +557,37 @@ impl Doc {
             .unwrap_or(TextAlignment::Left)
     }
 
-    /// needs a rename to 'get_header_font_size'
-    fn get_block_font_size(block: &ContentField) -> f32 {
+    /// style every text run of a block starts from, headings take the size, weight and colour of their level
+    fn get_block_text_style(block: &ContentField, heading_styles: &HeadingStyles) -> TextStyle<'static> {
+        match block.block_type {
+            BlockType::Heading => {
+                let heading_style = heading_styles.level(Doc::get_heading_level(block));
+
+                TextStyle::new(heading_style.size)
+                    .with_weight(heading_style.weight)
+                    .and_color(heading_style.color)
+            },
+            _ => TextStyle::new(12.0)
+        }
+    }
+
+    /// `language` attribute of a code block, `None` when it is missing or has no grammar
+    #[cfg(feature = "highlight")]
+    fn get_code_language(block: &ContentField) -> Option<Language> {
+        block
+            .attributes
+            .as_ref()
+            .and_then(|attribute_field| attribute_field.language.as_deref())
+            .and_then(Language::from_name)
+    }
+
+    /// `level` attribute of a heading, level 1 when it is missing
+    fn get_heading_level(block: &ContentField) -> u8 {
         block
             .attributes
             .as_ref()
             .and_then(|attribute_field| attribute_field.level)
-            .map(|level| match level {
-                1 => 16.0,
-                2 => 15.0,
-                3 => 14.0,
-                _ => 12.0,
-            })
-            .unwrap_or(12.0)
+            .unwrap_or(1)
     }
 
     /// calculates the offset required to center a line
@@ -205,58 +608,163 @@ impl Doc {
         }
     }
 
-    /// Entry point: builds the `Writer` struct and registers pre-provided fonts, outputs a finished PDF
-    pub fn render(&mut self) {
+    /// Entry point: builds the `Writer` struct and registers pre-provided fonts, returns the finished PDF as bytes
+    pub fn render(&mut self) -> Result<Vec<u8>, RenderError> {
+        // embedded families replace Times Roman for all text unless another family was chosen
+        let default_family = match (self.font_family, &self.embedded_font) {
+            (Some(font_family), _) => font_family,
+            (None, Some(_)) => FontFamily::Embedded,
+            (None, None) => FontFamily::TimesRoman,
+        };
+
+        // only the families used by the document are registered
+        let mut families: Vec<FontFamily> = vec![default_family];
+
+        let heading_families = self.heading_styles.levels.iter().filter_map(|heading_style| heading_style.family.as_ref());
+
+        for family in self.block_families.values().chain(heading_families).chain([&self.code_style.family]) {
+            if !families.contains(family) {
+                families.push(*family);
+            }
+        }
+
+        // standard 14 width providers are borrowed by the `Writer`, so they must outlive it
+        let standard_fonts: Vec<(FontFamily, Font)> = families
+            .iter()
+            .filter_map(|family| Font::standard(*family).map(|font| (*family, font)))
+            .collect();
+
         let mut pdf = Pdf::new();
         let mut secondary = Chunk::new();
-        let mut write_head = Writer::default();
+        let mut write_head = Writer::new(&self.page_setup);
 
 
         let page_tree_id = write_head.bump();
 
-        let times_normal = FontReference {
-            label: "times-normal",
-            name: Name(b"Times-Roman"),
-            id: write_head.bump()
-        };
-        let times_bold = FontReference {
-            label: "times-bold",
-            name: Name(b"Times-Bold"),
-            id: write_head.bump()
-        };
-        let times_italic = FontReference {
-            label: "times-italic",
-            name: Name(b"Times-Italic"),
-            id: write_head.bump()
-        };
-        let times_bold_italic = FontReference {
-            label: "times-bold-italic",
-            name: Name(b"Times-BoldItalic"),
-            id: write_head.bump()
-        };
-        
-        write_head.go_to(0.0 + write_head.page_margin, write_head.page_height-write_head.page_margin);
-        
-        write_head.font_refs.push(times_normal);
-        write_head.font_refs.push(times_bold);
-        write_head.font_refs.push(times_italic);
-        write_head.font_refs.push(times_bold_italic);
-        write_head.font_family.insert("times-roman", Font::new());
-        
-        for ref_obj in write_head.font_refs.iter() {
-            pdf.type1_font(ref_obj.id).base_font(ref_obj.name);
+        // headers and footers shrink the body, so they are set before the writer moves to the top of it
+        write_head.header = self.header.clone();
+        write_head.footer = self.footer.clone();
+        write_head.go_to(write_head.page_margins.left, write_head.top());
+        write_head.default_family = default_family;
+        write_head.block_families = self.block_families.clone();
+        write_head.link_style = self.link_style;
+        write_head.line_breaking = self.line_breaking;
+        write_head.hyphenation = self.hyphenation;
+        write_head.justification = self.justification;
+        write_head.pagination = self.pagination.clone();
+        write_head.heading_styles = self.heading_styles;
+        write_head.quote_style = self.quote_style;
+        write_head.code_style = self.code_style;
+        write_head.rule_style = self.rule_style;
+
+        // Symbol and ZapfDingbats keep their built-in encoding
+        for (family, font) in standard_fonts.iter() {
+            for (label, name) in family.standard_fonts() {
+                let id = write_head.bump();
+                let mut type1_font = pdf.type1_font(id);
+                type1_font.base_font(Name(name));
+
+                if font.encoding() == FontEncoding::WinAnsi {
+                    type1_font.encoding_predefined(Name(b"WinAnsiEncoding"));
+                }
+
+                write_head.font_refs.push(FontReference { id, label: label.to_string(), name: Name(name), font_file: None, encoding: font.encoding() });
+            }
+
+            write_head.font_family.insert(family.key(), font);
+        }
+
+        for family in families.iter() {
+            let FontFamily::Registered(name) = *family else { continue };
+
+            let registered = self.registered_fonts
+                .iter()
+                .find(|registered| registered.name == name)
+                .ok_or_else(|| RenderError::MissingFont(name.to_string()))?;
+
+            // fonts are labelled `{name}-{variant}`, so a name must not be another family's key or extend it with a
+            // `-` (`times-bold` would label its italic variant `times-bold-italic`, the label of Times BoldItalic)
+            let clashes = |key: &str| name.strip_prefix(key).is_some_and(|rest| rest.is_empty() || rest.starts_with('-'));
+            let reserved = [FontFamily::TimesRoman, FontFamily::Helvetica, FontFamily::Courier, FontFamily::Symbol, FontFamily::ZapfDingbats, FontFamily::Embedded];
+
+            if reserved.iter().any(|family| clashes(family.key())) {
+                return Err(RenderError::InvalidFont(format!("`{name}` is reserved for a built-in font family")));
+            }
+
+            let mut registered_names = families.iter().filter_map(|family| match family {
+                FontFamily::Registered(other) if *other != name => Some(*other),
+                _ => None
+            });
+
+            if let Some(other) = registered_names.find(|other| clashes(other)) {
+                return Err(RenderError::InvalidFont(format!("`{name}` clashes with the labels of the registered family `{other}`")));
+            }
+
+            for (variant, text_style, base_font) in registered.variants.fonts() {
+                let id = write_head.bump();
+                let descriptor_id = write_head.bump();
+                let name = Name(base_font.as_bytes());
+
+                Doc::write_registered_font(&mut pdf, id, descriptor_id, name, registered.widths.as_ref(), &text_style);
+                write_head.font_refs.push(FontReference { id, label: format!("{}-{variant}", family.key()), name, font_file: None, encoding: FontEncoding::WinAnsi });
+            }
+
+            write_head.font_family.insert(family.key(), registered.widths.as_ref());
+        }
+
+        // missing embedded variants fall back to the normal variant
+        // embedded fonts are written once the glyphs used by the document are known
+        if let Some(embedded_font) = &self.embedded_font {
+            let variants = [
+                ("embedded-normal", Name(b"Embedded-Normal"), Some(&embedded_font.normal)),
+                ("embedded-bold", Name(b"Embedded-Bold"), embedded_font.bold.as_ref()),
+                ("embedded-italic", Name(b"Embedded-Italic"), embedded_font.italic.as_ref()),
+                ("embedded-bold-italic", Name(b"Embedded-BoldItalic"), embedded_font.bold_italic.as_ref()),
+            ];
+
+            for (label, name, font_file) in variants {
+                if let Some(font_file) = font_file {
+                    let id = write_head.bump();
+                    write_head.font_refs.push(FontReference { id, label: label.to_string(), name, font_file: Some(font_file), encoding: FontEncoding::WinAnsi });
+                }
+            }
+
+            write_head.embedded_font = Some(embedded_font);
         }
 
         {
-            for block in self.content.iter_mut() {
+            let mut blocks: Vec<TextBlock> = Vec::with_capacity(self.content.len());
+
+            for block in self.content.iter() {
                 match block.block_type {
-                    BlockType::Heading => Doc::render_heading(&mut write_head, block),
-                    BlockType::OrderedList => Doc::render_ordered_list(&mut write_head, block),
-                    BlockType::Paragraph => Doc::render_paragraph(&mut write_head, block),
-                    _ => {} // non block levels excluded
+                    BlockType::Heading => blocks.push(Doc::layout_heading(&write_head, block, &Nesting::default())?),
+                    BlockType::OrderedList | BlockType::BulletList => Doc::layout_list(&write_head, block, &self.list_style, 0, &Nesting::default(), &mut blocks)?,
+                    BlockType::Paragraph => blocks.push(Doc::layout_paragraph(&write_head, block, &Nesting::default())?),
+                    BlockType::Blockquote => Doc::layout_blockquote(&write_head, block, &self.list_style, &Nesting::default(), &mut blocks)?,
+                    BlockType::CodeBlock => blocks.push(Doc::layout_code_block(&write_head, block, &Nesting::default())?),
+                    BlockType::HorizontalRule => blocks.push(Doc::layout_rule(&write_head, &Nesting::default())),
+                    // non block levels can't be rendered at the top level of a document
+                    _ => return Err(RenderError::UnsupportedNode(block.block_type))
                 }
             }
 
+            // lines are only written once every block is laid out, so each one lands on the page it was paginated to
+            let fragments = write_head.pagination.paginate(&blocks, write_head.top() - write_head.bottom());
+
+            write_head.write_fragments(&blocks, &fragments)?;
+
+            // headers and footers are written once the number of pages is known
+            Doc::write_headers_footers(&mut write_head, self.title.as_deref())?;
+
+            let embedded_refs: Vec<(Ref, &TrueTypeFont)> = write_head.font_refs
+                .iter()
+                .filter_map(|ref_obj| ref_obj.font_file.map(|font_file| (ref_obj.id, font_file)))
+                .collect();
+
+            for (font_id, font_file) in embedded_refs {
+                Doc::write_embedded_font(&mut pdf, &mut write_head, font_id, font_file)?;
+            }
+
             for page in write_head.pages.iter_mut() {
                 let mut pdf_page = pdf.page(page.page_id);
 
@@ -274,55 +782,63 @@ impl Doc {
                         fonts.pair(ref_obj.name, ref_obj.id);
                     }
                 }
-            }
 
+                if !page.links.is_empty() {
+                    pdf_page.annotations(page.links.iter().map(|link| link.id));
+                }
 
-            {
+                for link in page.links.drain(..) {
+                    let mut annotation = secondary.annotation(link.id);
 
-                let first_page_id_option = match write_head.pages.get(0) {
-                    Some(page) => Some(page.page_id),
-                    None => None
-                };
+                    annotation
+                        .subtype(AnnotationType::Link)
+                        .rect(link.rect)
+                        .border(0.0, 0.0, 0.0, None);
 
-                if let Some(first_page_id) = first_page_id_option {
-                    let annot_key = Name(b"Type");
-                    let annot_value = TextStr("Annot");
-                    let widget_key = Name(b"SubType");
-                    let widget_value = TextStr("Widget");
-                    let field_key = Name(b"Sig");
-                    let field_value = TextStr("Signature1");
-                    let rect_key = Name(b"Rect");
-                    let rect_value = Rect::new(0.0,0.0,100.0,100.0);
-    
-                    let sig_field_id = write_head.bump();
-                    let sig_annot_id = write_head.bump();
-                    let acroform_id  = write_head.bump();
-
-    
-                    let mut field = pdf.form_field(sig_annot_id);
-                    field.parent(first_page_id);
-                    field.pair(annot_key, annot_value);
-                    field.pair(widget_key, widget_value);
-                    field.pair(field_key, field_value);
-                    field.field_type(pdf_writer::types::FieldType::Signature);
-                    field.text_value(TextStr("etst"));
-                    field.partial_name(TextStr("Signature1"));
-                    field.pair(rect_key, rect_value);
-                    field.finish();
-
-                } else {
-                    println!("missing page_id");
+                    annotation
+                        .action()
+                        .action_type(ActionType::Uri)
+                        .uri(Str(link.uri.as_bytes()));
                 }
+            }
 
 
-                
+            {
+                let first_page_id = write_head.pages
+                    .first()
+                    .map(|page| page.page_id)
+                    .ok_or(RenderError::MissingPage)?;
+
+                let annot_key = Name(b"Type");
+                let annot_value = TextStr("Annot");
+                let widget_key = Name(b"SubType");
+                let widget_value = TextStr("Widget");
+                let field_key = Name(b"Sig");
+                let field_value = TextStr("Signature1");
+                let rect_key = Name(b"Rect");
+                let rect_value = Rect::new(0.0,0.0,100.0,100.0);
+
+                // reserved for the signature dictionary and AcroForm (0.2.0)
+                let _sig_field_id = write_head.bump();
+                let sig_annot_id = write_head.bump();
+                let _acroform_id  = write_head.bump();
+
+
+                let mut field = pdf.form_field(sig_annot_id);
+                field.parent(first_page_id);
+                field.pair(annot_key, annot_value);
+                field.pair(widget_key, widget_value);
+                field.pair(field_key, field_value);
+                field.field_type(pdf_writer::types::FieldType::Signature);
+                field.text_value(TextStr("etst"));
+                field.partial_name(TextStr("Signature1"));
+                field.pair(rect_key, rect_value);
+                field.finish();
             }
         }
 
         
 
-        // append footer to each page
-
         // Add the ExtG states to the PDF.
         pdf.extend(&secondary);
 
@@ -336,190 +852,852 @@ impl Doc {
         // Write the document catalog.
         pdf.catalog(write_head.bump()).pages(page_tree_id);
 
-        // Finish and write the thing to a file.
-        let _ = std::fs::write("./chunks.pdf", pdf.finish());
+        // Finish the thing and hand the bytes back to the caller.
+        Ok(pdf.finish())
     }
 
-    /// calls `render_text_block` method with no line indent
-    fn render_heading(write_head: &mut Writer, block: &mut ContentField) {
-        let indent: f32 = 0.0;
-        let post_block_offset = 0.0;
-        Doc::render_text_block(write_head, block, indent, post_block_offset);
+    /// writes a registered font as a WinAnsi Type1 font referenced by name, with a `Widths` array measured by its `FontType`
+    /// - a `FontType` only knows widths, so the font descriptor carries generic metrics the viewer uses to pick a substitute
+    fn write_registered_font(pdf: &mut Pdf, font_id: Ref, descriptor_id: Ref, base_font: Name, widths: &dyn FontType, text_style: &TextStyle) {
+        let bold = text_style.weight == FontWeight::Bold;
+        let italic = text_style.slant == FontSlant::Italic;
+        let text_style = TextStyle { size: 1000.0, ..*text_style };
+
+        pdf.type1_font(font_id)
+            .base_font(base_font)
+            .encoding_predefined(Name(b"WinAnsiEncoding"))
+            .first_char(0x20)
+            .last_char(0xFF)
+            // widths are measured at a font size of 1000, the glyph space of a Type1 font
+            .widths((0x20..=0xFF).map(|code| {
+                win_ansi_char(code)
+                    .map(|ch| widths.char_width(&ch, &text_style))
+                    .unwrap_or(0.0)
+            }))
+            .font_descriptor(descriptor_id);
+
+        let mut flags = FontFlags::NON_SYMBOLIC;
+
+        if italic {
+            flags |= FontFlags::ITALIC;
+        }
+
+        pdf.font_descriptor(descriptor_id)
+            .name(base_font)
+            .flags(flags)
+            .bbox(Rect::new(-200.0, -250.0, 1200.0, 950.0))
+            .italic_angle(if italic { -12.0 } else { 0.0 })
+            .ascent(750.0)
+            .descent(-250.0)
+            .cap_height(700.0)
+            .stem_v(if bold { 140.0 } else { 80.0 });
+    }
+
+    /// writes an embedded font as a Type0 font with a CIDFontType2 (TrueType) or CIDFontType0 (CFF) descendant,
+    /// its font descriptor, the subsetted font program and a ToUnicode CMap so text stays searchable
+    fn write_embedded_font(pdf: &mut Pdf, write_head: &mut Writer, font_id: Ref, font: &TrueTypeFont) -> Result<(), RenderError> {
+        let cid_font_id = write_head.bump();
+        let descriptor_id = write_head.bump();
+        let font_file_id = write_head.bump();
+        let cmap_id = write_head.bump();
+
+        let glyph_usage = write_head.glyph_usage.remove(&font_id).unwrap_or_default();
+        let glyphs: BTreeSet<u16> = glyph_usage.keys().copied().collect();
+        let font_program = font.subset(&glyphs)?;
+
+        let base_font = format!("{}+{}", Doc::subset_tag(&glyphs), font.postscript_name);
+        let base_font = Name(base_font.as_bytes());
+        let system_info = SystemInfo {
+            registry: Str(b"Adobe"),
+            ordering: Str(b"Identity"),
+            supplement: 0
+        };
+
+        pdf.type0_font(font_id)
+            .base_font(base_font)
+            .encoding_predefined(Name(b"Identity-H"))
+            .descendant_font(cid_font_id)
+            .to_unicode(cmap_id);
+
+        let mut cid_font = pdf.cid_font(cid_font_id);
+
+        cid_font
+            .subtype(if font.cff { CidFontType::Type0 } else { CidFontType::Type2 })
+            .base_font(base_font)
+            .system_info(system_info)
+            .font_descriptor(descriptor_id)
+            .default_width(font.glyph_advance(0));
+
+        if !font.cff {
+            cid_font.cid_to_gid_map_predefined(Name(b"Identity"));
+        }
+
+        // widths are written in runs of consecutive glyph ids
+        let mut widths = cid_font.widths();
+        let mut run: Vec<f32> = Vec::new();
+        let mut run_start: u16 = 0;
+
+        for glyph_id in glyphs.iter().copied() {
+            if !run.is_empty() && glyph_id != run_start + run.len() as u16 {
+                widths.consecutive(run_start, run.drain(..));
+            }
+
+            if run.is_empty() {
+                run_start = glyph_id;
+            }
+
+            run.push(font.glyph_advance(glyph_id));
+        }
+
+        if !run.is_empty() {
+            widths.consecutive(run_start, run.drain(..));
+        }
+
+        widths.finish();
+        cid_font.finish();
+
+        let mut flags = FontFlags::SYMBOLIC;
+
+        if font.italic_angle != 0.0 {
+            flags |= FontFlags::ITALIC;
+        }
+
+        if font.fixed_pitch {
+            flags |= FontFlags::FIXED_PITCH;
+        }
+
+        let bbox = Rect::new(
+            font.to_pdf_units(font.bbox[0] as f32),
+            font.to_pdf_units(font.bbox[1] as f32),
+            font.to_pdf_units(font.bbox[2] as f32),
+            font.to_pdf_units(font.bbox[3] as f32)
+        );
+
+        let mut descriptor = pdf.font_descriptor(descriptor_id);
+
+        descriptor
+            .name(base_font)
+            .flags(flags)
+            .bbox(bbox)
+            .italic_angle(font.italic_angle)
+            .ascent(font.to_pdf_units(font.ascent as f32))
+            .descent(font.to_pdf_units(font.descent as f32))
+            .cap_height(font.to_pdf_units(font.cap_height as f32))
+            // approximation of the dominant vertical stem width from the font weight
+            .stem_v(10.0 + 0.244 * (font.weight as f32 - 50.0));
+
+        if font.cff {
+            descriptor.font_file3(font_file_id);
+        } else {
+            descriptor.font_file2(font_file_id);
+        }
+
+        descriptor.finish();
+
+        let mut stream = pdf.stream(font_file_id, &font_program);
+
+        if font.cff {
+            stream.pair(Name(b"Subtype"), Name(b"OpenType"));
+        } else {
+            stream.pair(Name(b"Length1"), font_program.len() as i32);
+        }
+
+        stream.finish();
+
+        let mut cmap = UnicodeCmap::new(Name(b"Custom"), system_info);
+
+        for (glyph_id, ch) in glyph_usage {
+            cmap.pair(glyph_id, ch);
+        }
+
+        pdf.cmap(cmap_id, &cmap.finish());
+
+        Ok(())
     }
 
-    /// calls `render_text_block` method with no line indent
-    fn render_paragraph(write_head: &mut Writer, block: &mut ContentField) {
-        let indent: f32 = 0.0;
+    /// six uppercase letters derived from the glyph set, prefixed to the name of a subsetted font
+    fn subset_tag(glyphs: &BTreeSet<u16>) -> String {
+        // FNV-1a keeps the tag stable between renders of the same document
+        let mut hash: u32 = 0x811C9DC5;
+
+        for glyph_id in glyphs {
+            for byte in glyph_id.to_be_bytes() {
+                hash ^= byte as u32;
+                hash = hash.wrapping_mul(0x01000193);
+            }
+        }
+
+        (0..6)
+            .map(|_| {
+                let letter = (b'A' + (hash % 26) as u8) as char;
+                hash /= 26;
+                letter
+            })
+            .collect()
+    }
+
+    /// renders the document and writes the finished PDF to any `std::io::Write` destination (file, socket, response body...)
+    pub fn render_to(&mut self, mut destination: impl std::io::Write) -> Result<(), RenderError> {
+        let pdf = self.render()?;
+        destination.write_all(&pdf)?;
+        destination.flush()?;
+
+        Ok(())
+    }
+
+    /// calls `layout_text_block` method indented only by the blockquotes it is nested in, the heading is kept with the block following it
+    /// - spacing and family come from the `HeadingStyle` of the heading's level
+    fn layout_heading<'a>(write_head: &Writer, block: &'a ContentField, nesting: &Nesting) -> Result<TextBlock<'a>, RenderError> {
+        let heading_style = write_head.heading_styles.level(Doc::get_heading_level(block));
+        let indent: f32 = nesting.indent;
+        let post_block_offset = heading_style.space_after;
+        let family = heading_style.family.unwrap_or(write_head.family_for(block.block_type));
+        let text_block = Doc::layout_text_block(write_head, block, indent, post_block_offset, family, nesting)?
+            .and_pre_block_offset(heading_style.space_before)
+            .and_keep_together(write_head.pagination.keeps_together(block.block_type))
+            .and_keep_with_next(write_head.pagination.keep_headings_with_next);
+
+        Ok(text_block)
+    }
+
+    /// calls `layout_text_block` method indented only by the blockquotes it is nested in
+    fn layout_paragraph<'a>(write_head: &Writer, block: &'a ContentField, nesting: &Nesting) -> Result<TextBlock<'a>, RenderError> {
+        let indent: f32 = nesting.indent;
         let post_block_offset = 0.0;
-        Doc::render_text_block(write_head, block, indent, post_block_offset);
+        let family = write_head.family_for(block.block_type);
+        let text_block = Doc::layout_text_block(write_head, block, indent, post_block_offset, family, nesting)?
+            .and_keep_together(write_head.pagination.keeps_together(block.block_type));
+
+        Ok(text_block)
+    }
+
+    /// builds the `TextBlock` of a `horizontalRule`, a single line as tall as the rule is thick so it is paginated like text
+    /// - the rule runs from the indent of the blockquotes it is nested in to the right margin
+    fn layout_rule<'a>(write_head: &Writer, nesting: &Nesting) -> TextBlock<'a> {
+        let rule_style = write_head.rule_style;
+
+        // the writer sits at the top of a block without text
+        TextBlock::new()
+            .with_font_size(0.0)
+            .and_indent(nesting.indent)
+            .and_pre_block_offset(rule_style.space_before)
+            .and_post_block_offset(rule_style.space_after)
+            .and_quote_rules(nesting.quote_rules.clone())
+            .and_rule(Some(rule_style))
+    }
+
+    /// writes the header and footer slots of every page, their variables filled in
+    /// - the header sits at the top margin, the footer's descenders at the bottom margin
+    fn write_headers_footers(write_head: &mut Writer, title: Option<&str>) -> Result<(), RenderError> {
+        let pages = write_head.pages.len();
+        let writeable_area = write_head.writeable_width();
+        let family = write_head.default_family;
+        let header = write_head.header
+            .clone()
+            .map(|header| (write_head.page_height - write_head.page_margins.top, header));
+        // the footer line leaves room for descenders below its baseline, like any other line
+        let footer = write_head.footer
+            .clone()
+            .map(|footer| (write_head.page_margins.bottom + footer.font_size * 0.5, footer));
+
+        for (y, region) in header.into_iter().chain(footer) {
+            let mut date = String::new();
+
+            // chrono reports a format it can't parse when it is written out
+            write!(date, "{}", Local::now().format(&region.date_format))
+                .map_err(|_| RenderError::InvalidDateFormat(region.date_format.clone()))?;
+            let text_style = TextStyle::new(region.font_size);
+
+            for page in 0..pages {
+                let slots = region.slots_for(page + 1);
+                let mut texts: Vec<(f32, String)> = Vec::with_capacity(3);
+
+                for (slot, alignment) in [(&slots.left, TextAlignment::Left), (&slots.center, TextAlignment::Center), (&slots.right, TextAlignment::Right)] {
+                    let text = region.expand(slot, page + 1, pages, title, &date);
+
+                    if text.is_empty() {
+                        continue;
+                    }
+
+                    let width = Doc::word_width(&text, &family, &text_style, write_head)?;
+                    let offset = match alignment {
+                        TextAlignment::Center => Doc::offset_center(width, writeable_area),
+                        TextAlignment::Right => Doc::offset_right_justify(width, writeable_area),
+                        _ => 0.0
+                    };
+
+                    texts.push((write_head.page_margins.left + offset, text));
+                }
+
+                write_head.write_running_text(page, y, &texts, region.font_size)?;
+            }
+        }
+
+        Ok(())
     }
 
-    /// accepts a block, inserts the list number for each list item and calls `render_text_block()`
-    fn render_ordered_list(write_head: &mut Writer, block: &mut ContentField) {
-        let font_size = Doc::get_block_font_size(block);
-        let indent = font_size;
+    /// accepts an `orderedList` or `bulletList` block, hangs a number or bullet in front of each list item and pushes the
+    /// `TextBlock` of each one built by `layout_text_block()` onto `blocks`
+    /// - each nesting level is indented by `ListStyle.indent` and restarts its own counter
+    /// - ordered and bullet lists can be nested inside each other
+    /// - `Pagination.keep_together` with `BlockType::ListItem` keeps the text of each item on one page
+    fn layout_list<'a>(write_head: &Writer, block: &'a ContentField, list_style: &ListStyle, depth: usize, nesting: &Nesting, blocks: &mut Vec<TextBlock<'a>>) -> Result<(), RenderError> {
+        let font_size = Doc::get_block_text_style(block, &write_head.heading_styles).size;
+        let indent = nesting.indent + list_style.indent * (depth + 1) as f32;
+        let post_block_offset: f32 = font_size * 1.5;
+        let family = write_head.family_for(block.block_type);
         let mut counter = block.attributes
             .as_ref()
             .and_then(|attribute_field| attribute_field.list_start)
             .unwrap_or(1);
-    
-        if let Some(items) = block.content.as_mut() {
+
+        if let Some(items) = &block.content {
             for item in items {
-                if let Some(ref mut children) = item.content {
-                    if let Some(text) = Doc::find_first_text_node_mut(children) {
-                        
-                        let mut num_string = counter.to_string();
-                        num_string.push_str(". ");
-
-                        text.insert_str(0, &num_string);
-                        
-                        counter += 1;
-                    }
+                // only `listItem` nodes can carry a number or bullet
+                if item.block_type != BlockType::ListItem {
+                    return Err(RenderError::UnsupportedNode(item.block_type));
+                }
+
+                let label_text = match block.block_type {
+                    BlockType::OrderedList => format!("{counter}."),
+                    _ => list_style.bullet(depth).to_string()
+                };
+                let label_width = Doc::word_width(&label_text, &family, &TextStyle::new(font_size), write_head)?;
+                let mut label = Some(Label { text: label_text, width: label_width, indent, font_size, font_family: family });
+                let first = blocks.len();
 
+                counter += 1;
+
+                if let Some(children) = &item.content {
                     for child in children {
-                        let post_block_offset: f32 = font_size * 1.5;
-                        Doc::render_text_block(write_head, child, indent, post_block_offset);
+                        match child.block_type {
+                            BlockType::OrderedList | BlockType::BulletList => Doc::layout_list(write_head, child, list_style, depth + 1, nesting, blocks)?,
+                            // a quote in a list item lines up with the item's text
+                            BlockType::Blockquote => {
+                                let item_nesting = Nesting { indent, ..nesting.clone() };
+
+                                Doc::layout_blockquote(write_head, child, list_style, &item_nesting, blocks)?;
+                            },
+                            BlockType::CodeBlock => {
+                                let item_nesting = Nesting { indent, ..nesting.clone() };
+
+                                blocks.push(Doc::layout_code_block(write_head, child, &item_nesting)?);
+                            },
+                            BlockType::HorizontalRule => {
+                                let item_nesting = Nesting { indent, ..nesting.clone() };
+
+                                blocks.push(Doc::layout_rule(write_head, &item_nesting));
+                            },
+                            _ => {
+                                let keep_together = write_head.pagination.keeps_together(BlockType::ListItem)
+                                    || write_head.pagination.keeps_together(child.block_type);
+                                let text_block = Doc::layout_text_block(write_head, child, indent, post_block_offset, family, nesting)?
+                                    .and_keep_together(keep_together);
+
+                                blocks.push(text_block);
+                            }
+                        }
+
+                        // only the first block of a list item gets the label, ahead of the label of a nested list
+                        if let Some(first_block) = blocks.get_mut(first) {
+                            if let Some(label) = label.take() {
+                                first_block.labels.insert(0, label);
+                            }
+                        }
                     }
                 }
             }
         }
+
+        Ok(())
+    }
+
+    /// accepts a `blockquote` block and pushes the `TextBlock` of each block inside it onto `blocks`
+    /// - each level of quoting is indented by `QuoteStyle.indent` and adds a rule, drawn down the lines of every block inside it
+    /// - paragraphs, headings, lists, code blocks, rules and further quotes can be nested inside a quote
+    fn layout_blockquote<'a>(write_head: &Writer, block: &'a ContentField, list_style: &ListStyle, nesting: &Nesting, blocks: &mut Vec<TextBlock<'a>>) -> Result<(), RenderError> {
+        let quote_style = write_head.quote_style;
+        let mut quoted = nesting.clone();
+
+        if quote_style.rule_width > 0.0 {
+            quoted.quote_rules.push(QuoteRule { x: nesting.indent, width: quote_style.rule_width, color: quote_style.rule_color });
+        }
+
+        quoted.indent += quote_style.indent;
+
+        if quote_style.italic {
+            quoted.slant = FontSlant::Italic;
+        }
+
+        for child in block.content.iter().flatten() {
+            match child.block_type {
+                BlockType::Heading => blocks.push(Doc::layout_heading(write_head, child, &quoted)?),
+                BlockType::OrderedList | BlockType::BulletList => Doc::layout_list(write_head, child, list_style, 0, &quoted, blocks)?,
+                BlockType::Paragraph => blocks.push(Doc::layout_paragraph(write_head, child, &quoted)?),
+                BlockType::Blockquote => Doc::layout_blockquote(write_head, child, list_style, &quoted, blocks)?,
+                BlockType::CodeBlock => blocks.push(Doc::layout_code_block(write_head, child, &quoted)?),
+                BlockType::HorizontalRule => blocks.push(Doc::layout_rule(write_head, &quoted)),
+                _ => return Err(RenderError::UnsupportedNode(child.block_type))
+            }
+        }
+
+        Ok(())
     }
 
-    /// accepts any block with a `content` field containing a `text` field`, then assembles each line of text and calls `.write()` method on the `Writer`
-    /// - adds additional `Page` containers as needed
+    /// accepts any block with a `content` field containing a `text` field`, then assembles each line of text into a `TextBlock`
     /// - creates `Line` containers
     /// - creates `Word` containers
-    /// - assembles the content into a `TextBlock` container
-    /// - calls the `.write()` method with an assembled `TextBlock`
-    fn render_text_block(write_head: &mut Writer, block: &ContentField, indent: f32, post_block_offset: f32) {
+    /// - assembles the content into a `TextBlock` container, which `Pagination::paginate()` splits across pages
+    /// - takes the slant and rules of the blockquotes it is nested in
+    fn layout_text_block<'a>(write_head: &Writer, block: &'a ContentField, indent: f32, post_block_offset: f32, family: FontFamily, nesting: &Nesting) -> Result<TextBlock<'a>, RenderError> {
 
         if let Some(content) = &block.content {
 
             // basic block level styles
-            let font_size = Doc::get_block_font_size(block);
+            let block_style = Doc::get_block_text_style(block, &write_head.heading_styles).and_slant(nesting.slant);
+            let font_size = block_style.size;
             let alignment = Doc::get_block_text_alignment(block);
-            let writeable_area: f32 = write_head.page_width - (write_head.page_margin * 2.0) - indent;
+            let writeable_area: f32 = write_head.writeable_width() - indent;
+            let link_style = write_head.link_style;
+            let code_style = write_head.code_style;
 
             // build TextBlock
             let mut text_block = TextBlock::new()
                 .with_font_size(font_size)
                 .and_alignment(alignment)
-                .and_indent(indent);
+                .and_indent(indent)
+                .and_font_family(family)
+                .and_post_block_offset(post_block_offset)
+                .and_quote_rules(nesting.quote_rules.clone());
 
-            // build line
-            let mut line = &mut text_block.lines[text_block.index];
+            // the block's text nodes are joined so words continue across marks (`**Hel**lo`) and break only where
+            // Unicode line breaking allows it, hard breaks become mandatory breaks
+            let mut paragraph = String::new();
+            let mut runs: Vec<(usize, &str, TextStyle)> = Vec::with_capacity(content.len());
 
-            // iterate through each sub-section of a block assembling `TextBlock` objects and pushing them to the `Writer` for rendering to the `Content` chunk
             for section in content {
-
-                // get the `text` field
                 if let Some(text_string) = &section.text {
-                    // get section level styles
-                    let family = text_block.font_family;
-                    let font_style  = Doc::get_block_font_style(section);
-                    let attributes = Doc::get_block_attributes(section);
-                    let space_width = Doc::word_width(" ", font_size, &family, &font_style, write_head);
-                    
-                    // iterate over each word in the section and build add `Line` object to `TextBlock` object
-                    for text in text_string.split(' ') {
-
-                        // ignore empty strings & extra spaces, perhaps should reconsider? Double spaces will not render...ignore empty strings only?
-                        if text == " " || text.is_empty() { continue; }
-
-                        let text_width: f32 = Doc::word_width(text.trim(), font_size, &family, &font_style, write_head);
-                        let offset: f32 = space_width;
-                        
-                        // check if word will fit within the horizontal margins of a visible page
-                        if (line.width + text_width + offset) > writeable_area {
-                            
-                            // check if line will fit within the vertical margins of a visible page & create new `Page` when necessary
-                            if write_head.y - (font_size * 1.5) < write_head.page_margin {
-                                Doc::build_new_page(write_head);
-                            }
+                    // superscript and subscript runs are measured at the reduced size they are drawn at
+                    let text_style = Doc::get_text_style(section, &block_style, &link_style, &code_style);
 
-                            // build a new line and get a pointer to it
-                            text_block.next();
-                            line = &mut text_block.lines[text_block.index];
-                        }
+                    runs.push((paragraph.len(), text_string.as_str(), text_style));
+                    paragraph.push_str(text_string);
+                } else if section.block_type == BlockType::Break {
+                    paragraph.push('\n');
+                }
+            }
+
+            // justified text stretches and shrinks the spaces between words, ragged text only its line ends
+            let justified = alignment == TextAlignment::Justify;
+            let line_stretch = if justified { 0.0 } else { font_size * 3.0 };
+            let line_breaking = write_head.line_breaking;
+            let hyphenation = write_head.hyphenation;
+            let mut items: Vec<BreakItem> = Vec::new();
+            let mut segment_start: usize = 0;
+
+            // each segment runs up to a break opportunity and keeps its trailing spaces, which become glue after its words
+            for (segment_end, opportunity) in linebreaks(&paragraph) {
+                let text_end = segment_start + paragraph[segment_start..segment_end].trim_end_matches(Doc::is_breaking_space).len();
+                let text = &paragraph[segment_start..text_end];
+
+                // hyphenation points split the segment into pieces, a hyphen is drawn when a line ends between two of them
+                let mut pieces: Vec<usize> = vec![segment_start];
+
+                if let Some(hyphenation) = hyphenation {
+                    pieces.extend(hyphenation.points(text).into_iter().map(|point| segment_start + point));
+                }
+
+                pieces.push(text_end);
+
+                for (index, piece) in pieces.windows(2).enumerate() {
+                    if index > 0 {
+                        let hyphen = Doc::run_hyphen(&runs, piece[0], &family, write_head)?;
+                        items.push(BreakItem::Penalty { penalty: line_breaking.hyphen_penalty(), flagged: true, hyphen });
+                    }
+
+                    let words = Doc::run_words(&runs, piece[0], piece[1], &family, write_head)?;
+                    let width = words.iter().map(|word| word.width).sum();
 
-                        let word = Word {
-                            attributes,
-                            font_style: font_style.clone(),
-                            offset,
-                            text,
-                            width: text_width
-                        };
-
-                        // push the built word onto the line
-                        line.width += text_width + offset;
-                        line.body.push(word);
+                    if !words.is_empty() {
+                        items.push(BreakItem::Box { words, width });
                     }
-                } else {
-                    // executes when no text field found
-                    let font_size = Doc::get_block_font_size(block);
-                    let text_block = TextBlock::new()
-                        .with_font_size(font_size);
-
-                    // check if line will fit within the vertical margins of a visible page & create new `Page` when necessary
-                    if write_head.y - (font_size * 1.5) < write_head.page_margin {
-                        Doc::build_new_page(write_head);
+                }
+
+                let space_width = Doc::run_spaces_width(&runs, text_end, segment_end, &family, write_head)?;
+
+                match opportunity {
+                    // a hard break (or a line feed in the text) ends the line, its trailing spaces are dropped
+                    BreakOpportunity::Mandatory => items.extend(BreakItem::forced_break()),
+                    BreakOpportunity::Allowed if text_end < segment_end => items.push(BreakItem::Glue {
+                        text: Doc::glue_text(Doc::run_text(&runs, text_end, segment_end)),
+                        width: space_width,
+                        stretch: if justified { space_width / 2.0 } else { 0.0 },
+                        shrink: if justified { space_width / 3.0 } else { 0.0 }
+                    }),
+                    // breaks without a space: after a hyphen or dash, between ideographs
+                    BreakOpportunity::Allowed => items.push(BreakItem::Penalty {
+                        penalty: 0.0,
+                        flagged: text.ends_with(['-', '\u{2010}']),
+                        hyphen: None
+                    }),
+                }
+
+                segment_start = segment_end;
+            }
+
+            let mut breaks = line_breaking
+                .breaks(&items, writeable_area, line_stretch)
+                .into_iter()
+                .peekable();
+
+            // build line
+            let mut line = &mut text_block.lines[text_block.index];
+            // glue and penalties at the start of a line are discarded
+            let mut line_start = true;
+
+            for (index, item) in items.into_iter().enumerate() {
+                if breaks.next_if_eq(&index).is_some() {
+                    line.hard_break = item.is_forced();
+
+                    if let BreakItem::Penalty { hyphen: Some(hyphen), .. } = item {
+                        line.width += hyphen.width;
+                        line.body.push(hyphen);
                     }
 
-                    write_head.write(text_block);
+                    // the last break ends the block
+                    if breaks.peek().is_some() {
+                        // build a new line and get a pointer to it
+                        text_block.next();
+                        line = &mut text_block.lines[text_block.index];
+                        line_start = true;
+                    }
+
+                    continue;
+                }
+
+                match item {
+                    // push the built words onto the line
+                    BreakItem::Box { words, .. } => {
+                        for word in words {
+                            line.width += word.width;
+                            line.font_size = line.font_size.max(word.text_style.font_size());
+                            line.body.push(word);
+                        }
+
+                        line_start = false;
+                    },
+                    // spaces between segments become the offset of the word before them
+                    BreakItem::Glue { text, width, .. } if !line_start => {
+                        if let Some(word) = line.body.last_mut() {
+                            word.space = text;
+                            word.offset += width;
+                            line.width += width;
+                        }
+                    },
+                    _ => {}
                 }
             }
 
-            Doc::apply_text_alignment(&mut text_block, writeable_area);
+            Doc::apply_text_alignment(&mut text_block, writeable_area, &write_head.justification);
 
-            write_head.write(text_block);
+            Ok(text_block)
         } else {
-            // executes when no content field found
-            let font_size = Doc::get_block_font_size(block);
+            // executes when no content field found, the empty block still takes up a line
             let text_block = TextBlock::new()
-                .with_font_size(font_size);
+                .with_font_size(Doc::get_block_text_style(block, &write_head.heading_styles).size)
+                .and_indent(indent)
+                .and_font_family(family)
+                .and_post_block_offset(post_block_offset)
+                .and_quote_rules(nesting.quote_rules.clone());
+
+            Ok(text_block)
+        }
+    }
+
+    /// accepts a `codeBlock` block and lays out its text in the `CodeStyle` family, whitespace kept as written
+    /// - every line feed (or hard break) starts a new line, tabs move on to the next tab stop
+    /// - lines wider than the block are wrapped with a continuation marker or clipped (`CodeOverflow`), never justified
+    /// - the lines are boxed in the `CodeStyle` background, the text inset by its padding
+    /// - with the `highlight` feature, the tokens of a known `language` are drawn in the colours of `CodeStyle.theme`
+    fn layout_code_block<'a>(write_head: &Writer, block: &'a ContentField, nesting: &Nesting) -> Result<TextBlock<'a>, RenderError> {
+        let code_style = write_head.code_style;
+        let indent = nesting.indent + code_style.padding;
+        let writeable_area = write_head.writeable_width() - indent - code_style.padding;
+        let text_style = TextStyle::new(code_style.font_size);
+        let runs: Vec<(&str, TextStyle)> = block.content
+            .iter()
+            .flatten()
+            .filter_map(|section| match section.block_type {
+                BlockType::Break => Some(("\n", text_style)),
+                _ => section.text.as_deref().map(|text_string| (text_string, text_style))
+            })
+            .collect();
+
+        // tokens of a known language are coloured by the theme
+        #[cfg(feature = "highlight")]
+        let runs = match (Doc::get_code_language(block), code_style.theme) {
+            (Some(language), Some(theme)) => runs
+                .iter()
+                .flat_map(|(text_string, text_style)| {
+                    language
+                        .tokenize(text_string)
+                        .into_iter()
+                        .map(move |(token, kind)| (token, text_style.and_color(theme.color(kind))))
+                })
+                .collect(),
+            _ => runs
+        };
+
+        // the space around the box is twice its padding, half of it is taken by the box
+        let mut text_block = TextBlock::new()
+            .with_font_size(code_style.font_size)
+            .and_indent(indent)
+            .and_font_family(code_style.family)
+            .and_pre_block_offset(code_style.padding * 2.0)
+            .and_post_block_offset(code_style.padding * 2.0)
+            .and_keep_together(write_head.pagination.keeps_together(block.block_type))
+            .and_quote_rules(nesting.quote_rules.clone())
+            .and_background(code_style.background)
+            .and_padding(code_style.padding)
+            .and_clip(code_style.overflow == CodeOverflow::Clip);
+
+        Doc::layout_code_lines(write_head, &mut text_block, &runs, writeable_area)?;
+
+        Ok(text_block)
+    }
+
+    /// pushes runs of code onto the lines of `text_block`, helper method for `layout_code_block`
+    /// - each line of source is one `Word` per run, its spaces drawn as they are
+    fn layout_code_lines<'a>(write_head: &Writer, text_block: &mut TextBlock<'a>, runs: &[(&'a str, TextStyle<'a>)], writeable_area: f32) -> Result<(), RenderError> {
+        let code_style = write_head.code_style;
+        let family = text_block.font_family;
+        let tab_width = code_style.tab_width.max(1);
+        let space_width = Doc::word_width(" ", &family, &TextStyle::new(text_block.font_size), write_head)?;
+        // characters since the start of the source line, tab stops are counted in columns
+        let mut column: usize = 0;
+
+        for (text_string, text_style) in runs {
+            let marker_width = match (code_style.overflow, code_style.continuation.is_empty()) {
+                (CodeOverflow::Wrap, false) => Doc::word_width(code_style.continuation, &family, text_style, write_head)?,
+                _ => 0.0
+            };
+
+            for piece in text_string.split_inclusive(['\n', '\t']) {
+                let (text, delimiter) = match piece.chars().last() {
+                    Some(ch @ ('\n' | '\t')) => (piece[..piece.len() - 1].trim_end_matches('\r'), Some(ch)),
+                    _ => (piece, None)
+                };
+                let mut rest = text;
+
+                while !rest.is_empty() {
+                    let line = &mut text_block.lines[text_block.index];
+                    // a clipped source line is kept whole, the writer cuts it off at the edge of the block
+                    let room = match code_style.overflow {
+                        CodeOverflow::Clip => f32::INFINITY,
+                        CodeOverflow::Wrap => writeable_area - line.width
+                    };
+                    let (mut fit, mut width) = Doc::code_fit(rest, room, &family, text_style, write_head)?;
+
+                    // a wrapped line keeps room for its marker
+                    if fit < rest.len() && code_style.overflow == CodeOverflow::Wrap {
+                        (fit, width) = Doc::code_fit(rest, room - marker_width, &family, text_style, write_head)?;
+                    }
+
+                    // a line too narrow for a single character still takes one
+                    if fit == 0 && line.body.is_empty() {
+                        fit = rest.chars().next().map_or(0, char::len_utf8);
+                        width = Doc::word_width(&rest[..fit], &family, text_style, write_head)?;
+                    }
+
+                    if fit > 0 {
+                        line.width += width;
+                        line.font_size = line.font_size.max(text_style.font_size());
+                        line.body.push(Word { text_style: *text_style, offset: 0.0, text: &rest[..fit], space: "", width });
+                    }
+
+                    column += rest[..fit].chars().count();
+                    rest = &rest[fit..];
+
+                    if rest.is_empty() {
+                        break;
+                    }
+
+                    // the marker sits against the right edge of the block
+                    if marker_width > 0.0 {
+                        let gap = (writeable_area - line.width - marker_width).max(0.0);
+
+                        match line.body.last_mut() {
+                            Some(word) => word.offset += gap,
+                            None => line.offset += gap
+                        }
 
-            // check if line will fit within the vertical margins of a visible page & create new `Page` when necessary
-            if write_head.y - (font_size * 1.5) < write_head.page_margin {
-                Doc::build_new_page(write_head);
+                        line.width += gap + marker_width;
+                        line.font_size = line.font_size.max(text_style.font_size());
+                        line.body.push(Word { text_style: *text_style, offset: 0.0, text: code_style.continuation, space: "", width: marker_width });
+                    }
+
+                    text_block.next();
+                }
+
+                match delimiter {
+                    Some('\n') => {
+                        text_block.next();
+                        column = 0;
+                    },
+                    Some(_) => {
+                        let stop = (column / tab_width + 1) * tab_width;
+                        let advance = (stop - column) as f32 * space_width;
+                        let line = &mut text_block.lines[text_block.index];
+
+                        match line.body.last_mut() {
+                            Some(word) => word.offset += advance,
+                            None => line.offset += advance
+                        }
+
+                        line.width += advance;
+                        column = stop;
+                    },
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// length and width of the longest start of `text` no wider than `room`, helper method for `layout_code_lines`
+    fn code_fit(text: &str, room: f32, family: &FontFamily, text_style: &TextStyle, write_head: &Writer) -> Result<(usize, f32), RenderError> {
+        let mut fit: usize = 0;
+        let mut width: f32 = 0.0;
+
+        for (index, ch) in text.char_indices() {
+            let end = index + ch.len_utf8();
+            let char_width = Doc::word_width(&text[index..end], family, text_style, write_head)?;
+
+            if width + char_width > room {
+                break;
             }
 
-            write_head.write(text_block);
+            fit = end;
+            width += char_width;
         }
 
-        write_head.feed(post_block_offset);
+        Ok((fit, width))
     }
 
-    fn build_new_page(write_head: &mut Writer) {
-        let new_page_id = write_head.bump();
-        let new_content_id = write_head.bump();
-        let new_content = Content::new();
-        let page_content = PageContent {
-            content_id: new_content_id,
-            content: new_content
-        };
+    /// one `Word` for each text node overlapping `start..end` of a block's joined text, drawn with no space between them
+    fn run_words<'a>(runs: &[(usize, &'a str, TextStyle<'a>)], start: usize, end: usize, family: &FontFamily, write_head: &Writer) -> Result<Vec<Word<'a>>, RenderError> {
+        let mut words: Vec<Word> = Vec::new();
+
+        for (run_start, text_string, text_style) in runs.iter() {
+            let (from, to) = (start.max(*run_start), end.min(run_start + text_string.len()));
+
+            if from < to {
+                let text = &text_string[from - run_start..to - run_start];
+
+                words.push(Word {
+                    text_style: *text_style,
+                    offset: 0.0,
+                    text,
+                    space: "",
+                    width: Doc::word_width(text, family, text_style, write_head)?
+                });
+            }
+        }
 
-        write_head.pages.push(Page {
-            page_id: new_page_id, 
-            contents: Vec::from([page_content])
-        });
+        Ok(words)
+    }
+
+    /// width of the spaces in `start..end` of a block's joined text, each measured in the style of its text node
+    /// - tabs and other breaking whitespace have no glyph of their own and are measured as a space
+    fn run_spaces_width(runs: &[(usize, &str, TextStyle)], start: usize, end: usize, family: &FontFamily, write_head: &Writer) -> Result<f32, RenderError> {
+        let mut width: f32 = 0.0;
+
+        for (run_start, text_string, text_style) in runs.iter() {
+            let (from, to) = (start.max(*run_start), end.min(run_start + text_string.len()));
+
+            if from < to {
+                let spaces = text_string[from - run_start..to - run_start].chars().count() as f32;
+
+                width += Doc::word_width(" ", family, text_style, write_head)? * spaces;
+            }
+        }
+
+        Ok(width)
+    }
+
+    /// spaces drawn for the breaking whitespace between two segments, one space for each whitespace character
+    fn glue_text(text: &str) -> &str {
+        match text.chars().all(|ch| ch == ' ') {
+            true => text,
+            false => &SPACES[..text.chars().count().min(SPACES.len())]
+        }
+    }
+
+    /// text of `start..end` of a block's joined text within the first text node it overlaps
+    fn run_text<'a>(runs: &[(usize, &'a str, TextStyle<'a>)], start: usize, end: usize) -> &'a str {
+        runs.iter()
+            .find(|(run_start, text_string, _)| start.max(*run_start) < end.min(run_start + text_string.len()))
+            .map(|(run_start, text_string, _)| {
+                &text_string[start.max(*run_start) - run_start..end.min(run_start + text_string.len()) - run_start]
+            })
+            .unwrap_or("")
+    }
 
-        write_head.current_page = Some(new_page_id);
-        write_head.y = write_head.page_height - write_head.page_margin;
+    /// hyphen drawn in the style of the letter in front of a hyphenation point
+    fn run_hyphen<'a>(runs: &[(usize, &'a str, TextStyle<'a>)], point: usize, family: &FontFamily, write_head: &Writer) -> Result<Option<Word<'a>>, RenderError> {
+        let run = runs
+            .iter()
+            .find(|(run_start, text_string, _)| *run_start < point && point <= run_start + text_string.len());
+
+        match run {
+            Some((_, _, text_style)) => Ok(Some(Word {
+                text_style: *text_style,
+                offset: 0.0,
+                text: "-",
+                space: "",
+                width: Doc::word_width("-", family, text_style, write_head)?
+            })),
+            None => Ok(None)
+        }
+    }
+
+    /// spaces a line may break after, trailing a segment they are measured but never drawn at the end of a line
+    /// - no-break spaces keep the words on either side together and are drawn like any other character
+    fn is_breaking_space(ch: char) -> bool {
+        ch.is_whitespace() && !matches!(ch, '\u{00A0}' | '\u{2007}' | '\u{202F}')
     }
 
-    /// helper method for `render_text_block`
-    fn word_width(word: &str, font_size: f32, family: &FontFamily, font_style: &Style, write_head: &Writer) -> f32 {
+    /// helper method for `layout_text_block`
+    fn word_width(word: &str, family: &FontFamily, text_style: &TextStyle, write_head: &Writer) -> Result<f32, RenderError> {
 
-        // the internals will need to be reworked to allow externally registered fonts
         let mut current_width: f32 = 0.0;
+        // inline code is measured in the family it is drawn with
+        let family = &text_style.family.unwrap_or(*family);
+
+        // registered families may lack the variant the style asks for
+        let text_style = match *family {
+            FontFamily::Registered(_) => &write_head.measured_style(*family, text_style),
+            _ => text_style
+        };
 
         for ch in word.chars() {
             current_width += match *family {
-                    FontFamily::TimesRoman => write_head.get_char_width(&ch, font_size, font_style, "times-roman")
+                    FontFamily::Embedded => write_head.get_embedded_char_width(&ch, text_style)?,
+                    // measure the characters the WinAnsi font will actually draw, a ligature is drawn as its letters
+                    FontFamily::Registered(_) => match (decompose_ligature(ch), win_ansi_substitute(ch)) {
+                        (Some(letters), _) => letters
+                            .chars()
+                            .map(|letter| write_head.get_char_width(&letter, text_style, family.key()))
+                            .sum::<Result<f32, RenderError>>()?,
+                        (None, Some(ch)) => write_head.get_char_width(&ch, text_style, family.key())?,
+                        (None, None) => 0.0
+                    },
+                    _ => write_head.get_char_width(&ch, text_style, family.key())?
             };
         }
 
-        current_width
+        Ok(current_width)
     }
 }
 
@@ -528,6 +1706,24 @@ impl Default for Doc {
         Doc {
             doc_type: None,
             content: Vec::with_capacity(20),
+            page_setup: PageSetup::default(),
+            list_style: ListStyle::default(),
+            embedded_font: None,
+            font_family: None,
+            block_families: HashMap::new(),
+            registered_fonts: Vec::new(),
+            link_style: LinkStyle::default(),
+            line_breaking: LineBreaking::default(),
+            hyphenation: None,
+            justification: Justification::default(),
+            pagination: Pagination::default(),
+            header: None,
+            footer: None,
+            title: None,
+            heading_styles: HeadingStyles::default(),
+            quote_style: QuoteStyle::default(),
+            code_style: CodeStyle::default(),
+            rule_style: RuleStyle::default(),
         }
     }
 }
\ No newline at end of file
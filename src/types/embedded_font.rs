@@ -0,0 +1,68 @@
+use crate::types::{ FontSlant, FontWeight, TextStyle, TrueTypeFont };
+
+/// A font family embedded in the PDF from TrueType/OpenType files. Only the normal variant is required,
+/// missing variants fall back to it.
+/// 
+/// # Example
+/// ```no_run
+/// # use edra::types::{ EmbeddedFont, TrueTypeFont };
+/// let regular = TrueTypeFont::from_bytes(std::fs::read("./Brand-Regular.ttf").unwrap()).unwrap();
+/// let bold = TrueTypeFont::from_bytes(std::fs::read("./Brand-Bold.ttf").unwrap()).unwrap();
+/// 
+/// let font = EmbeddedFont::new(regular) // TrueTypeFont
+///     .and_bold(bold);                   // TrueTypeFont
+/// ```
+#[derive(Debug,Clone)]
+pub struct EmbeddedFont {
+    pub normal: TrueTypeFont,
+    pub bold: Option<TrueTypeFont>,
+    pub italic: Option<TrueTypeFont>,
+    pub bold_italic: Option<TrueTypeFont>,
+}
+
+impl EmbeddedFont {
+    /// creates a family from its normal (regular) variant
+    pub fn new(normal: TrueTypeFont) -> Self {
+        EmbeddedFont {
+            normal,
+            bold: None,
+            italic: None,
+            bold_italic: None
+        }
+    }
+
+    /// builder function setting the bold variant
+    pub fn and_bold(mut self, bold: TrueTypeFont) -> Self {
+        self.bold = Some(bold);
+        self
+    }
+
+    /// builder function setting the italic variant
+    pub fn and_italic(mut self, italic: TrueTypeFont) -> Self {
+        self.italic = Some(italic);
+        self
+    }
+
+    /// builder function setting the bold-italic variant
+    pub fn and_bold_italic(mut self, bold_italic: TrueTypeFont) -> Self {
+        self.bold_italic = Some(bold_italic);
+        self
+    }
+
+    /// returns the font file drawing the style's weight and slant, falling back to the normal variant
+    pub fn variant(&self, text_style: &TextStyle) -> &TrueTypeFont {
+        let variant = match (text_style.weight, text_style.slant) {
+            (FontWeight::Normal, FontSlant::Normal) => None,
+            (FontWeight::Bold, FontSlant::Normal) => self.bold.as_ref(),
+            (FontWeight::Normal, FontSlant::Italic) => self.italic.as_ref(),
+            (FontWeight::Bold, FontSlant::Italic) => self.bold_italic.as_ref(),
+        };
+
+        variant.unwrap_or(&self.normal)
+    }
+
+    /// width of a character in the variant used for `text_style`, measured from the font's `hmtx` table
+    pub fn char_width(&self, ch: &char, text_style: &TextStyle) -> f32 {
+        self.variant(text_style).char_width(ch, text_style.font_size())
+    }
+}
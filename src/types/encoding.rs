@@ -0,0 +1,133 @@
+use crate::types::standard_metrics::{ SYMBOL_CODES, ZAPF_DINGBATS_CODES };
+
+// characters Windows-1252 places in the 0x80 - 0x9F range, which Latin-1 leaves to control codes
+const WIN_ANSI_HIGH: [(char, u8); 27] = [
+    ('€', 0x80), ('‚', 0x82), ('ƒ', 0x83), ('„', 0x84), ('…', 0x85), ('†', 0x86),
+    ('‡', 0x87), ('ˆ', 0x88), ('‰', 0x89), ('Š', 0x8A), ('‹', 0x8B), ('Œ', 0x8C),
+    ('Ž', 0x8E), ('‘', 0x91), ('’', 0x92), ('“', 0x93), ('”', 0x94), ('•', 0x95),
+    ('–', 0x96), ('—', 0x97), ('˜', 0x98), ('™', 0x99), ('š', 0x9A), ('›', 0x9B),
+    ('œ', 0x9C), ('ž', 0x9E), ('Ÿ', 0x9F),
+];
+
+/// Maps a character to its single byte code in WinAnsiEncoding (Windows-1252), the encoding set on the standard 14 fonts.
+/// Returns `None` for characters the encoding can't represent.
+pub fn win_ansi(ch: char) -> Option<u8> {
+    let code = ch as u32;
+
+    match code {
+        // ASCII and the Latin-1 supplement map directly
+        0x20..=0x7E | 0xA0..=0xFF => Some(code as u8),
+        _ => WIN_ANSI_HIGH
+            .iter()
+            .find(|(key, _)| *key == ch)
+            .map(|(_, code)| *code)
+    }
+}
+
+/// Maps a WinAnsiEncoding code back to its character, `None` for control and unassigned codes
+pub fn win_ansi_char(code: u8) -> Option<char> {
+    match code {
+        0x20..=0x7E | 0xA0..=0xFF => Some(code as char),
+        _ => WIN_ANSI_HIGH
+            .iter()
+            .find(|(_, key)| *key == code)
+            .map(|(ch, _)| *ch)
+    }
+}
+
+// base letters for Latin Extended-A (U+0100 - U+017F), used when an accented letter has no WinAnsi code
+const LATIN_EXTENDED_A: &str = "AaAaAaCcCcCcCcDdDdEeEeEeEeEeGgGgGgGgHhHhIiIiIiIiIiIiJjKkkLlLlLlLlLlNnNnNnnNnOoOoOoOoRrRrRrSsSsSsSsTtTtTtUuUuUuUuUuUuWwYyYZzZzZzs";
+
+// letters joined by the Latin ligatures (U+FB00 - U+FB06), long s included
+const LIGATURES: [&str; 7] = ["ff", "fi", "fl", "ffi", "ffl", "st", "st"];
+
+/// Splits a Latin ligature (`ﬁ`, `ﬂ`...) into the letters it joins, `None` for any other character.
+/// WinAnsiEncoding has no ligatures, so they are drawn as their letters rather than substituted.
+pub fn decompose_ligature(ch: char) -> Option<&'static str> {
+    match ch {
+        '\u{FB00}'..='\u{FB06}' => Some(LIGATURES[ch as usize - 0xFB00]),
+        _ => None
+    }
+}
+
+/// Returns the character actually drawn for `ch` by a WinAnsiEncoding font:
+/// - the character itself when the encoding can represent it
+/// - the closest representable character otherwise (`ł` → `l`, `‐` → `-`, thin space → space)
+/// - `?` when nothing comes close (CJK, emoji...), an embedded font is required for those
+/// - `None` for zero width and control characters, which are dropped
+pub fn win_ansi_substitute(ch: char) -> Option<char> {
+    if win_ansi(ch).is_some() {
+        return Some(ch);
+    }
+
+    match ch {
+        '\u{0100}'..='\u{017F}' => LATIN_EXTENDED_A.chars().nth(ch as usize - 0x100),
+        // romanian comma below
+        'Ș' => Some('S'),
+        'ș' => Some('s'),
+        'Ț' => Some('T'),
+        'ț' => Some('t'),
+        '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2043}' | '\u{2212}' => Some('-'),
+        '\u{2015}' => Some('—'),
+        '\u{201B}' | '\u{2032}' => Some('\''),
+        '\u{201F}' | '\u{2033}' => Some('"'),
+        '\u{2002}'..='\u{200A}' | '\u{205F}' | '\u{3000}' => Some(' '),
+        '\u{202F}' => Some('\u{00A0}'),
+        '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}' => None,
+        _ if ch.is_control() => None,
+        _ => Some('?')
+    }
+}
+
+/// encodes text for a WinAnsiEncoding font, substituting characters the encoding can't represent
+pub fn encode_win_ansi(text: &str) -> Vec<u8> {
+    FontEncoding::WinAnsi.encode(text)
+}
+
+/// Single byte encoding of a standard 14 font
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum FontEncoding {
+    /// Times, Helvetica and Courier, written with `/Encoding /WinAnsiEncoding`
+    WinAnsi,
+    /// built-in encoding of the Symbol font
+    Symbol,
+    /// built-in encoding of the ZapfDingbats font
+    ZapfDingbats,
+}
+
+impl FontEncoding {
+    /// returns the code drawn for `ch`, `None` for characters that are dropped
+    /// - WinAnsi substitutes characters it can't represent, see `win_ansi_substitute()`
+    /// - Symbol draws `?` and ZapfDingbats a space for characters missing from the font
+    pub fn code(&self, ch: char) -> Option<u8> {
+        match self {
+            FontEncoding::WinAnsi => win_ansi_substitute(ch).and_then(win_ansi),
+            FontEncoding::Symbol => FontEncoding::builtin_code(&SYMBOL_CODES, ch, b'?'),
+            FontEncoding::ZapfDingbats => FontEncoding::builtin_code(&ZAPF_DINGBATS_CODES, ch, b' '),
+        }
+    }
+
+    /// encodes text, dropping the characters `code()` has no code for
+    /// - WinAnsi splits ligatures into their letters before substituting, see `decompose_ligature()`
+    pub fn encode(&self, text: &str) -> Vec<u8> {
+        let mut encoded: Vec<u8> = Vec::with_capacity(text.len());
+
+        for ch in text.chars() {
+            match (self, decompose_ligature(ch)) {
+                (FontEncoding::WinAnsi, Some(letters)) => encoded.extend(letters.chars().filter_map(|letter| self.code(letter))),
+                _ => encoded.extend(self.code(ch))
+            }
+        }
+
+        encoded
+    }
+
+    // looks a character up in a table sorted by character
+    fn builtin_code(codes: &[(char, u8)], ch: char, fallback: u8) -> Option<u8> {
+        match codes.binary_search_by_key(&ch, |(key, _)| *key) {
+            Ok(index) => Some(codes[index].1),
+            Err(_) if ch.is_control() || matches!(ch, '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}') => None,
+            Err(_) => Some(fallback)
+        }
+    }
+}
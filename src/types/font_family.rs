@@ -1,4 +1,59 @@
-#[derive(Debug,Clone,Copy)]
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
 pub enum FontFamily {
-    TimesRoman
-}
\ No newline at end of file
+    /// Times-Roman, Times-Bold, Times-Italic, Times-BoldItalic
+    TimesRoman,
+    /// Helvetica, Helvetica-Bold, Helvetica-Oblique, Helvetica-BoldOblique
+    Helvetica,
+    /// Courier, Courier-Bold, Courier-Oblique, Courier-BoldOblique (monospaced)
+    Courier,
+    /// Greek letters and mathematical symbols, the single variant is used for every style
+    Symbol,
+    /// ornaments and dingbats, the single variant is used for every style
+    ZapfDingbats,
+    /// the `EmbeddedFont` registered with `Doc::with_embedded_font()`
+    Embedded,
+    /// a family registered under this name with `Doc::register_font()`
+    Registered(&'static str)
+}
+
+impl FontFamily {
+    /// prefix of the labels the family's fonts are registered under (`"times-bold"`, `"helvetica-normal"`...)
+    pub fn key(&self) -> &'static str {
+        match self {
+            FontFamily::TimesRoman => "times",
+            FontFamily::Helvetica => "helvetica",
+            FontFamily::Courier => "courier",
+            FontFamily::Symbol => "symbol",
+            FontFamily::ZapfDingbats => "zapf-dingbats",
+            FontFamily::Embedded => "embedded",
+            FontFamily::Registered(name) => name,
+        }
+    }
+
+    /// label and PostScript name of each standard 14 font in the family, empty for embedded and registered families
+    pub fn standard_fonts(&self) -> &'static [(&'static str, &'static [u8])] {
+        match self {
+            FontFamily::TimesRoman => &[
+                ("times-normal", b"Times-Roman"),
+                ("times-bold", b"Times-Bold"),
+                ("times-italic", b"Times-Italic"),
+                ("times-bold-italic", b"Times-BoldItalic"),
+            ],
+            FontFamily::Helvetica => &[
+                ("helvetica-normal", b"Helvetica"),
+                ("helvetica-bold", b"Helvetica-Bold"),
+                ("helvetica-italic", b"Helvetica-Oblique"),
+                ("helvetica-bold-italic", b"Helvetica-BoldOblique"),
+            ],
+            FontFamily::Courier => &[
+                ("courier-normal", b"Courier"),
+                ("courier-bold", b"Courier-Bold"),
+                ("courier-italic", b"Courier-Oblique"),
+                ("courier-bold-italic", b"Courier-BoldOblique"),
+            ],
+            FontFamily::Symbol => &[("symbol-normal", b"Symbol")],
+            FontFamily::ZapfDingbats => &[("zapf-dingbats-normal", b"ZapfDingbats")],
+            FontFamily::Embedded | FontFamily::Registered(_) => &[],
+        }
+    }
+}
@@ -1,8 +1,13 @@
 use pdf_writer::{ Name, Ref };
+use crate::types::{ FontEncoding, TrueTypeFont };
 
 #[derive(Debug)]
 pub struct FontReference<'a> {
     pub id: Ref,
-    pub label: &'a str,
+    pub label: String,
     pub name: Name<'a>,
+    /// font program embedded as a Type0 font, `None` for the standard 14 fonts
+    pub font_file: Option<&'a TrueTypeFont>,
+    /// single byte encoding of a standard 14 font, unused when `font_file` is set
+    pub encoding: FontEncoding,
 }
@@ -0,0 +1,80 @@
+// size of body text, keyword and `rem` sizes are relative to it like they are to the root font size in the editor
+const BODY_FONT_SIZE: f32 = 12.0;
+
+/// Font size of a text run, parsed from the `fontSize` attribute of Edra's `textStyle` mark
+///
+/// # Example
+/// ```
+/// # use edra::types::FontSize;
+/// assert_eq!(FontSize::parse("24px"), Some(FontSize::Points(18.0)));
+/// assert_eq!(FontSize::parse("large").map(|size| size.resolve(12.0)), Some(15.0));
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum FontSize {
+    Tiny,
+    Smaller,
+    Small,
+    Default,
+    Large,
+    ExtraLarge,
+    /// absolute size in points (`pt`, or `px` at 96dpi)
+    Points(f32),
+    /// multiple of the body font size (`rem`)
+    Body(f32),
+    /// multiple of the font size of the surrounding block (`em`, `%`)
+    Block(f32),
+}
+
+impl FontSize {
+    /// parses a CSS font size, `None` when the value is empty or not understood
+    /// - keywords: `tiny`, `smaller`, `small`, `default`, `large`, `extra-large` and the CSS `x-small`... `xx-large` names
+    /// - units: `pt`, `px`, `rem`, `em`, `%`, numbers without a unit are read as `px`
+    pub fn parse(value: &str) -> Option<FontSize> {
+        let value = value.trim().to_ascii_lowercase();
+
+        let keyword = match value.as_str() {
+            "tiny" | "xx-small" => Some(FontSize::Tiny),
+            "smaller" | "x-small" => Some(FontSize::Smaller),
+            "small" => Some(FontSize::Small),
+            "default" | "normal" | "medium" => Some(FontSize::Default),
+            "large" => Some(FontSize::Large),
+            "extra-large" | "extra large" | "x-large" => Some(FontSize::ExtraLarge),
+            "xx-large" => Some(FontSize::Body(2.0)),
+            _ => None
+        };
+
+        if keyword.is_some() {
+            return keyword;
+        }
+
+        let split = value
+            .find(|ch: char| !(ch.is_ascii_digit() || ch == '.'))
+            .unwrap_or(value.len());
+        let (number, unit) = value.split_at(split);
+        let number: f32 = number.parse().ok().filter(|number: &f32| *number > 0.0)?;
+
+        match unit.trim() {
+            "pt" => Some(FontSize::Points(number)),
+            "px" | "" => Some(FontSize::Points(number * 0.75)),
+            "rem" => Some(FontSize::Body(number)),
+            "em" => Some(FontSize::Block(number)),
+            "%" => Some(FontSize::Block(number / 100.0)),
+            _ => None
+        }
+    }
+
+    /// size in points of a run inside a block set at `block_font_size`
+    pub fn resolve(&self, block_font_size: f32) -> f32 {
+        match *self {
+            FontSize::Tiny => BODY_FONT_SIZE * 0.7,
+            FontSize::Smaller => BODY_FONT_SIZE * 0.8,
+            FontSize::Small => BODY_FONT_SIZE * 0.9,
+            FontSize::Default => BODY_FONT_SIZE,
+            FontSize::Large => BODY_FONT_SIZE * 1.25,
+            FontSize::ExtraLarge => BODY_FONT_SIZE * 1.5,
+            FontSize::Points(points) => points,
+            FontSize::Body(multiple) => BODY_FONT_SIZE * multiple,
+            FontSize::Block(multiple) => block_font_size * multiple,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::types::{ FontSlant, FontWeight, TextStyle };
+
+/// PostScript names of the fonts drawing each variant of a family registered with `Doc::register_font()`.
+/// Only the normal variant is required, missing variants fall back to it.
+///
+/// # Example
+/// ```
+/// # use edra::types::FontVariants;
+/// let variants = FontVariants::new("Garamond")
+///     .and_bold("Garamond-Bold")
+///     .and_italic("Garamond-Italic");
+/// ```
+#[derive(Debug,Clone)]
+pub struct FontVariants {
+    pub normal: String,
+    pub bold: Option<String>,
+    pub italic: Option<String>,
+    pub bold_italic: Option<String>,
+}
+
+impl FontVariants {
+    /// creates the variants from the PostScript name of the normal (regular) font
+    pub fn new(normal: impl Into<String>) -> Self {
+        FontVariants {
+            normal: normal.into(),
+            bold: None,
+            italic: None,
+            bold_italic: None
+        }
+    }
+
+    /// builder function setting the bold variant
+    pub fn and_bold(mut self, bold: impl Into<String>) -> Self {
+        self.bold = Some(bold.into());
+        self
+    }
+
+    /// builder function setting the italic variant
+    pub fn and_italic(mut self, italic: impl Into<String>) -> Self {
+        self.italic = Some(italic.into());
+        self
+    }
+
+    /// builder function setting the bold-italic variant
+    pub fn and_bold_italic(mut self, bold_italic: impl Into<String>) -> Self {
+        self.bold_italic = Some(bold_italic.into());
+        self
+    }
+
+    /// label suffix, measured `TextStyle` and PostScript name of each variant that was set
+    pub fn fonts(&self) -> Vec<(&'static str, TextStyle<'static>, &str)> {
+        let bold = TextStyle::default().with_weight(FontWeight::Bold);
+        let italic = TextStyle::default().and_slant(FontSlant::Italic);
+        let mut fonts = vec![("normal", TextStyle::default(), self.normal.as_str())];
+
+        if let Some(name) = &self.bold {
+            fonts.push(("bold", bold, name.as_str()));
+        }
+
+        if let Some(name) = &self.italic {
+            fonts.push(("italic", italic, name.as_str()));
+        }
+
+        if let Some(name) = &self.bold_italic {
+            fonts.push(("bold-italic", bold.and_slant(FontSlant::Italic), name.as_str()));
+        }
+
+        fonts
+    }
+}
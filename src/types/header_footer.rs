@@ -0,0 +1,142 @@
+/// Text in the left, centre and right of a header or footer, empty slots are left blank
+/// - `{page}`, `{pages}`, `{title}` and `{date}` are replaced on each page
+///
+/// # Example
+/// ```
+/// # use edra::types::Slots;
+/// let slots = Slots::new()
+///     .with_left("{title}")                  // &str
+///     .and_center("")                        // &str
+///     .and_right("Page {page} of {pages}");  // &str
+/// ```
+#[derive(Debug,Clone,PartialEq,Default)]
+pub struct Slots {
+    pub left: String,
+    pub center: String,
+    pub right: String,
+}
+
+impl Slots {
+    /// default settings:
+    /// - All slots blank
+    pub fn new() -> Self {
+        Slots::default()
+    }
+
+    /// builder function setting the text aligned with the left margin
+    pub fn with_left(mut self, left: &str) -> Self {
+        self.left = left.to_string();
+        self
+    }
+
+    /// builder function setting the text centred between the margins
+    pub fn and_center(mut self, center: &str) -> Self {
+        self.center = center.to_string();
+        self
+    }
+
+    /// builder function setting the text aligned with the right margin
+    pub fn and_right(mut self, right: &str) -> Self {
+        self.right = right.to_string();
+        self
+    }
+}
+
+/// Header or footer repeated on every page, the body text is shrunk to leave room for it
+///
+/// # Example
+/// ```
+/// # use edra::types::{ HeaderFooter, Slots };
+/// let footer = HeaderFooter::new(Slots::new().and_right("{page} / {pages}"))
+///     .with_first_page(Slots::new())                          // Slots, blank on the first page
+///     .and_even_pages(Slots::new().with_left("{page} / {pages}")) // Slots, mirrored on even pages
+///     .and_font_size(9.0)                                     // f32
+///     .and_spacing(12.0)                                      // f32
+///     .and_date_format("%d %B %Y");                           // &str, chrono format of `{date}`
+///
+/// assert_eq!(footer.expand("{page} / {pages}", 2, 7, None, ""), "2 / 7");
+/// ```
+#[derive(Debug,Clone,PartialEq)]
+pub struct HeaderFooter {
+    /// slots of every page without a variant of its own
+    pub slots: Slots,
+    /// slots of the first page
+    pub first_page: Option<Slots>,
+    /// slots of even (left hand) pages, odd pages use `slots`
+    pub even_pages: Option<Slots>,
+    pub font_size: f32,
+    /// space between the header or footer and the body text
+    pub spacing: f32,
+    /// chrono format string `{date}` is written with, rendering fails on a format chrono can't parse
+    pub date_format: String,
+}
+
+impl HeaderFooter {
+    /// default settings:
+    /// - Same slots on every page
+    /// - Font size: 9.0
+    /// - Spacing: 12.0
+    /// - Date format: 2025-01-31
+    pub fn new(slots: Slots) -> Self {
+        HeaderFooter {
+            slots,
+            first_page: None,
+            even_pages: None,
+            font_size: 9.0,
+            spacing: 12.0,
+            date_format: "%Y-%m-%d".to_string(),
+        }
+    }
+
+    /// builder function setting the slots of the first page
+    pub fn with_first_page(mut self, first_page: Slots) -> Self {
+        self.first_page = Some(first_page);
+        self
+    }
+
+    /// builder function setting the slots of even pages
+    pub fn and_even_pages(mut self, even_pages: Slots) -> Self {
+        self.even_pages = Some(even_pages);
+        self
+    }
+
+    /// builder function setting the font size
+    pub fn and_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// builder function setting the space between the header or footer and the body text
+    pub fn and_spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// builder function setting the chrono format string of `{date}`
+    pub fn and_date_format(mut self, date_format: &str) -> Self {
+        self.date_format = date_format.to_string();
+        self
+    }
+
+    /// slots drawn on a one based page number
+    pub fn slots_for(&self, page: usize) -> &Slots {
+        match (&self.first_page, &self.even_pages) {
+            (Some(first_page), _) if page == 1 => first_page,
+            (_, Some(even_pages)) if page.is_multiple_of(2) => even_pages,
+            _ => &self.slots
+        }
+    }
+
+    /// height taken away from the body text, a line of text plus the spacing
+    pub fn height(&self) -> f32 {
+        self.font_size * 1.5 + self.spacing
+    }
+
+    /// replaces the variables of a slot, `{title}` is blank for a document without a title
+    pub fn expand(&self, slot: &str, page: usize, pages: usize, title: Option<&str>, date: &str) -> String {
+        slot.replace("{page}", &page.to_string())
+            .replace("{pages}", &pages.to_string())
+            .replace("{title}", title.unwrap_or_default())
+            .replace("{date}", date)
+    }
+}
@@ -0,0 +1,127 @@
+use crate::types::{ Color, FontFamily, FontWeight };
+
+/// Look of one heading level
+///
+/// # Example
+/// ```
+/// # use edra::types::{ Color, FontFamily, FontWeight, HeadingStyle };
+/// let heading_style = HeadingStyle::new(18.0)            // f32, font size
+///     .with_weight(FontWeight::Normal)                  // FontWeight
+///     .and_family(Some(FontFamily::Helvetica))          // Option<FontFamily>, the heading family when `None`
+///     .and_color(Some(Color::new(31, 56, 100)))         // Option<Color>
+///     .and_space_before(14.0)                           // f32
+///     .and_space_after(7.0);                            // f32
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct HeadingStyle {
+    pub size: f32,
+    pub weight: FontWeight,
+    /// family of the level, headings use the family set for `BlockType::Heading` when `None`
+    pub family: Option<FontFamily>,
+    /// fill colour, black when `None`
+    pub color: Option<Color>,
+    /// space above the heading, dropped at the top of a page
+    pub space_before: f32,
+    /// space between the heading and the block following it
+    pub space_after: f32,
+}
+
+impl HeadingStyle {
+    /// default settings:
+    /// - Weight: bold
+    /// - Family: the heading family
+    /// - Colour: black
+    /// - Space before: 3/4 of the size
+    /// - Space after: 3/8 of the size
+    pub fn new(size: f32) -> Self {
+        HeadingStyle {
+            size,
+            weight: FontWeight::Bold,
+            family: None,
+            color: None,
+            space_before: size * 0.75,
+            space_after: size * 0.375,
+        }
+    }
+
+    /// builder function setting the font weight
+    pub fn with_weight(mut self, weight: FontWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// builder function setting the font family
+    pub fn and_family(mut self, family: Option<FontFamily>) -> Self {
+        self.family = family;
+        self
+    }
+
+    /// builder function setting the fill colour
+    pub fn and_color(mut self, color: Option<Color>) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// builder function setting the space above the heading
+    pub fn and_space_before(mut self, space_before: f32) -> Self {
+        self.space_before = space_before;
+        self
+    }
+
+    /// builder function setting the space below the heading
+    pub fn and_space_after(mut self, space_after: f32) -> Self {
+        self.space_after = space_after;
+        self
+    }
+}
+
+/// Style sheet of the six Edra heading levels (`attrs.level`)
+///
+/// # Example
+/// ```
+/// # use edra::types::{ HeadingStyle, HeadingStyles };
+/// let heading_styles = HeadingStyles::new()
+///     .with_level(1, HeadingStyle::new(28.0));   // u8 from 1 to 6, HeadingStyle
+///
+/// assert_eq!(heading_styles.level(1).size, 28.0);
+/// assert_eq!(heading_styles.level(9).size, heading_styles.level(6).size);
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct HeadingStyles {
+    pub levels: [HeadingStyle; 6],
+}
+
+impl HeadingStyles {
+    /// default settings, a typographic scale over 12pt body text:
+    /// - H1: 24.0
+    /// - H2: 20.0
+    /// - H3: 17.0
+    /// - H4: 14.0
+    /// - H5: 12.0
+    /// - H6: 11.0
+    pub fn new() -> Self {
+        HeadingStyles::default()
+    }
+
+    /// builder function setting the style of a level, levels outside 1 to 6 are ignored
+    pub fn with_level(mut self, level: u8, heading_style: HeadingStyle) -> Self {
+        if (1..=6).contains(&level) {
+            self.levels[level as usize - 1] = heading_style;
+        }
+
+        self
+    }
+
+    /// style of a level, levels outside 1 to 6 are clamped to the nearest one
+    pub fn level(&self, level: u8) -> &HeadingStyle {
+        &self.levels[level.clamp(1, 6) as usize - 1]
+    }
+}
+
+impl Default for HeadingStyles {
+    fn default() -> Self {
+        HeadingStyles {
+            levels: [24.0, 20.0, 17.0, 14.0, 12.0, 11.0].map(HeadingStyle::new),
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use crate::types::TokenKind;
+
+/// Languages the `highlight` feature tokenizes, read from the `language` attribute of a `codeBlock`
+///
+/// # Example
+/// ```
+/// # use edra::types::{ Language, TokenKind };
+/// let language = Language::from_name("rs").expect("unknown language");
+///
+/// assert_eq!(language.tokenize("let x = 1;"), vec![
+///     ("let", TokenKind::Keyword),
+///     (" x = ", TokenKind::Plain),
+///     ("1", TokenKind::Number),
+///     (";", TokenKind::Plain),
+/// ]);
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum Language {
+    Rust,
+    /// JavaScript and TypeScript, JSX included
+    JavaScript,
+    Python,
+    Json,
+    Sql,
+    /// POSIX shell and Bash
+    Shell,
+}
+
+// words and delimiters a language is tokenized by
+struct Grammar {
+    keywords: &'static [&'static str],
+    types: &'static [&'static str],
+    literals: &'static [&'static str],
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    // opening delimiters of strings, closed by the same delimiter, longest first
+    strings: &'static [&'static str],
+    // `'a'` is a character, `'a` a lifetime
+    char_literals: bool,
+    // names followed by `!` are macro calls
+    macros: bool,
+    // capitalised names are types
+    capitalized_types: bool,
+    case_insensitive: bool,
+    // characters allowed in names besides letters, digits and `_`
+    name_chars: &'static [char],
+}
+
+const RUST: Grammar = Grammar {
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "fn", "for", "if", "impl",
+        "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super",
+        "trait", "type", "unsafe", "use", "where", "while",
+    ],
+    types: &[
+        "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize", "f32", "f64",
+    ],
+    literals: &["true", "false"],
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    strings: &["\""],
+    char_literals: true,
+    macros: true,
+    capitalized_types: true,
+    case_insensitive: false,
+    name_chars: &[],
+};
+
+const JAVASCRIPT: Grammar = Grammar {
+    keywords: &[
+        "abstract", "as", "async", "await", "break", "case", "catch", "class", "const", "continue", "debugger", "declare", "default",
+        "delete", "do", "else", "enum", "export", "extends", "finally", "for", "from", "function", "get", "if", "implements",
+        "import", "in", "instanceof", "interface", "let", "new", "of", "private", "protected", "public", "readonly", "return",
+        "set", "static", "super", "switch", "this", "throw", "try", "type", "typeof", "var", "void", "while", "yield",
+    ],
+    types: &["any", "bigint", "boolean", "never", "number", "object", "string", "symbol", "unknown"],
+    literals: &["true", "false", "null", "undefined", "NaN", "Infinity"],
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    strings: &["\"", "'", "`"],
+    char_literals: false,
+    macros: false,
+    capitalized_types: true,
+    case_insensitive: false,
+    name_chars: &['$'],
+};
+
+const PYTHON: Grammar = Grammar {
+    keywords: &[
+        "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif", "else", "except", "finally",
+        "for", "from", "global", "if", "import", "in", "is", "lambda", "match", "nonlocal", "not", "or", "pass", "raise", "return",
+        "try", "while", "with", "yield",
+    ],
+    types: &["bool", "bytes", "dict", "float", "int", "list", "object", "set", "str", "tuple"],
+    literals: &["True", "False", "None"],
+    line_comments: &["#"],
+    block_comment: None,
+    strings: &["\"\"\"", "'''", "\"", "'"],
+    char_literals: false,
+    macros: false,
+    capitalized_types: true,
+    case_insensitive: false,
+    name_chars: &[],
+};
+
+const JSON: Grammar = Grammar {
+    keywords: &[],
+    types: &[],
+    literals: &["true", "false", "null"],
+    line_comments: &[],
+    block_comment: None,
+    strings: &["\""],
+    char_literals: false,
+    macros: false,
+    capitalized_types: false,
+    case_insensitive: false,
+    name_chars: &[],
+};
+
+const SQL: Grammar = Grammar {
+    keywords: &[
+        "add", "all", "alter", "and", "as", "asc", "begin", "between", "by", "case", "check", "commit", "constraint", "create",
+        "cross", "default", "delete", "desc", "distinct", "drop", "else", "end", "exists", "foreign", "from", "full", "group",
+        "having", "in", "index", "inner", "insert", "into", "is", "join", "key", "left", "like", "limit", "not", "offset", "on",
+        "or", "order", "outer", "primary", "references", "returning", "right", "rollback", "select", "set", "table", "then",
+        "union", "unique", "update", "values", "view", "when", "where", "with",
+    ],
+    types: &[
+        "bigint", "blob", "boolean", "char", "date", "decimal", "double", "float", "int", "integer", "numeric", "real", "serial",
+        "smallint", "text", "time", "timestamp", "uuid", "varchar",
+    ],
+    literals: &["true", "false", "null"],
+    line_comments: &["--"],
+    block_comment: Some(("/*", "*/")),
+    strings: &["'", "\""],
+    char_literals: false,
+    macros: false,
+    capitalized_types: false,
+    case_insensitive: true,
+    name_chars: &[],
+};
+
+const SHELL: Grammar = Grammar {
+    keywords: &[
+        "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function", "if", "in", "local", "readonly", "return",
+        "select", "then", "until", "while",
+    ],
+    types: &[],
+    literals: &["true", "false"],
+    line_comments: &["#"],
+    block_comment: None,
+    strings: &["\"", "'"],
+    char_literals: false,
+    macros: false,
+    capitalized_types: false,
+    case_insensitive: false,
+    name_chars: &['$', '-'],
+};
+
+impl Language {
+    /// language of a `language` attribute (`"rust"`, `"rs"`, `"ts"`, `"bash"`...), `None` for languages without a grammar
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "rust" | "rs" => Some(Language::Rust),
+            "javascript" | "js" | "jsx" | "mjs" | "typescript" | "ts" | "tsx" => Some(Language::JavaScript),
+            "python" | "py" => Some(Language::Python),
+            "json" | "jsonc" => Some(Language::Json),
+            "sql" | "postgresql" | "postgres" | "mysql" | "sqlite" => Some(Language::Sql),
+            "shell" | "sh" | "bash" | "zsh" | "console" => Some(Language::Shell),
+            _ => None
+        }
+    }
+
+    fn grammar(&self) -> &'static Grammar {
+        match self {
+            Language::Rust => &RUST,
+            Language::JavaScript => &JAVASCRIPT,
+            Language::Python => &PYTHON,
+            Language::Json => &JSON,
+            Language::Sql => &SQL,
+            Language::Shell => &SHELL,
+        }
+    }
+
+    /// splits `text` into tokens, together they are the whole of `text` with whitespace and line feeds left in place
+    /// - neighbouring tokens of the same kind are joined
+    /// - unterminated strings and comments run to the end of the text
+    pub fn tokenize<'a>(&self, text: &'a str) -> Vec<(&'a str, TokenKind)> {
+        let grammar = self.grammar();
+        let mut tokens: Vec<(&'a str, TokenKind)> = Vec::new();
+        let mut start: usize = 0;
+
+        while let Some(ch) = text[start..].chars().next() {
+            let rest = &text[start..];
+            let (length, kind) = Language::token(grammar, text, start, ch, rest);
+
+            match tokens.last_mut() {
+                Some((token, last_kind)) if *last_kind == kind => *token = &text[start - token.len()..start + length],
+                _ => tokens.push((&rest[..length], kind))
+            }
+
+            start += length;
+        }
+
+        tokens
+    }
+
+    // length and kind of the token at the start of `rest`
+    fn token(grammar: &Grammar, text: &str, start: usize, ch: char, rest: &str) -> (usize, TokenKind) {
+        if let Some(prefix) = grammar.line_comments.iter().find(|prefix| rest.starts_with(**prefix)) {
+            // `#` only starts a comment at the start of a word (`$#` and `a#b` in shell)
+            let word_start = text[..start].chars().next_back().is_none_or(char::is_whitespace);
+
+            if *prefix != "#" || word_start {
+                return (rest.find('\n').unwrap_or(rest.len()), TokenKind::Comment);
+            }
+        }
+
+        if let Some((open, close)) = grammar.block_comment.filter(|(open, _)| rest.starts_with(*open)) {
+            let length = rest[open.len()..].find(close).map_or(rest.len(), |end| open.len() + end + close.len());
+            return (length, TokenKind::Comment);
+        }
+
+        if let Some(delimiter) = grammar.strings.iter().find(|delimiter| rest.starts_with(**delimiter)) {
+            return (Language::string_length(rest, delimiter), TokenKind::String);
+        }
+
+        if ch == '\'' && grammar.char_literals {
+            return match Language::char_literal_length(rest) {
+                Some(length) => (length, TokenKind::String),
+                // a lifetime or label
+                None => (1, TokenKind::Plain)
+            };
+        }
+
+        if ch.is_ascii_digit() {
+            return (Language::number_length(rest), TokenKind::Number);
+        }
+
+        if Language::is_name_char(grammar, ch) && !ch.is_ascii_digit() {
+            let length = rest.find(|ch: char| !Language::is_name_char(grammar, ch)).unwrap_or(rest.len());
+            let kind = Language::classify(grammar, &rest[..length], rest[length..].trim_start_matches([' ', '\t']));
+
+            return (length, kind);
+        }
+
+        (ch.len_utf8(), TokenKind::Plain)
+    }
+
+    // length of a string opened by `delimiter`, backslashes escape the character after them
+    fn string_length(rest: &str, delimiter: &str) -> usize {
+        let mut index = delimiter.len();
+
+        while let Some(ch) = rest[index..].chars().next() {
+            if ch == '\\' {
+                index += 1;
+                index += rest[index..].chars().next().map_or(0, char::len_utf8);
+            } else if rest[index..].starts_with(delimiter) {
+                return index + delimiter.len();
+            } else {
+                index += ch.len_utf8();
+            }
+        }
+
+        rest.len()
+    }
+
+    // length of a character literal (`'a'`, `'\n'`, `'\u{41}'`), `None` when the quote starts a lifetime
+    fn char_literal_length(rest: &str) -> Option<usize> {
+        let mut chars = rest.char_indices().skip(1);
+
+        match chars.next()? {
+            (index, '\\') => {
+                let after = index + 1 + rest[index + 1..].chars().next()?.len_utf8();
+
+                rest[after..].find('\'').filter(|end| *end < 10).map(|end| after + end + 1)
+            },
+            (_, '\n') => None,
+            (index, ch) => rest[index + ch.len_utf8()..].starts_with('\'').then_some(index + ch.len_utf8() + 1)
+        }
+    }
+
+    // digits, radix prefixes, suffixes and exponents, a decimal point only when a digit follows it (`1..2` is a range)
+    fn number_length(rest: &str) -> usize {
+        let bytes = rest.as_bytes();
+        let mut index: usize = 0;
+
+        while index < bytes.len() {
+            match bytes[index] {
+                byte if byte.is_ascii_alphanumeric() || byte == b'_' => index += 1,
+                b'.' if bytes.get(index + 1).is_some_and(u8::is_ascii_digit) => index += 1,
+                _ => break
+            }
+        }
+
+        index
+    }
+
+    fn is_name_char(grammar: &Grammar, ch: char) -> bool {
+        ch.is_alphanumeric() || ch == '_' || grammar.name_chars.contains(&ch)
+    }
+
+    // kind of a name, `following` is the text after it with spaces and tabs skipped
+    fn classify(grammar: &Grammar, name: &str, following: &str) -> TokenKind {
+        let matches = |words: &[&str]| match grammar.case_insensitive {
+            true => words.iter().any(|word| word.eq_ignore_ascii_case(name)),
+            false => words.contains(&name)
+        };
+
+        if matches(grammar.keywords) {
+            TokenKind::Keyword
+        } else if matches(grammar.literals) {
+            TokenKind::Literal
+        } else if matches(grammar.types) || (grammar.capitalized_types && name.starts_with(char::is_uppercase)) {
+            TokenKind::Type
+        } else if following.starts_with('(') || (grammar.macros && following.starts_with('!') && !following.starts_with("!=")) {
+            TokenKind::Function
+        } else {
+            TokenKind::Plain
+        }
+    }
+}
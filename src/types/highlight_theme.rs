@@ -0,0 +1,139 @@
+use crate::types::Color;
+
+/// Kind of a token, each is drawn in the colour `HighlightTheme` gives it
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum TokenKind {
+    Keyword,
+    /// built-in and capitalised type names
+    Type,
+    /// `true`, `false`, `null` and the like
+    Literal,
+    String,
+    Number,
+    Comment,
+    /// names followed by a call (or a Rust macro)
+    Function,
+    /// everything else: names, operators, punctuation and whitespace
+    Plain,
+}
+
+/// Colours of the tokens of highlighted code blocks, plain tokens keep the colour of the code block
+/// - code is only tokenized with the `highlight` feature, without it the theme is kept but never applied
+///
+/// # Example
+/// ```
+/// # use edra::types::{ Color, HighlightTheme, TokenKind };
+/// let theme = HighlightTheme::new()
+///     .with_keyword(Color::new(0, 0, 255))    // Color
+///     .and_type_name(Color::new(38, 127, 153)) // Color
+///     .and_literal(Color::new(0, 0, 255))      // Color
+///     .and_string(Color::new(163, 21, 21))     // Color
+///     .and_number(Color::new(9, 134, 88))      // Color
+///     .and_comment(Color::new(0, 128, 0))      // Color
+///     .and_function(Color::new(121, 94, 38))   // Color
+///     .and_plain(None);                        // Option<Color>
+///
+/// assert_eq!(theme.color(TokenKind::Keyword), Some(Color::new(0, 0, 255)));
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct HighlightTheme {
+    pub keyword: Color,
+    pub type_name: Color,
+    pub literal: Color,
+    pub string: Color,
+    pub number: Color,
+    pub comment: Color,
+    pub function: Color,
+    /// colour of everything else, black when `None`
+    pub plain: Option<Color>,
+}
+
+impl HighlightTheme {
+    /// default settings, a light theme for the default light grey background:
+    /// - Keywords: red
+    /// - Types: brown
+    /// - Literals & numbers: blue
+    /// - Strings: navy
+    /// - Comments: grey
+    /// - Functions: purple
+    pub fn new() -> Self {
+        HighlightTheme::default()
+    }
+
+    /// builder function setting the colour of keywords
+    pub fn with_keyword(mut self, keyword: Color) -> Self {
+        self.keyword = keyword;
+        self
+    }
+
+    /// builder function setting the colour of type names
+    pub fn and_type_name(mut self, type_name: Color) -> Self {
+        self.type_name = type_name;
+        self
+    }
+
+    /// builder function setting the colour of literals
+    pub fn and_literal(mut self, literal: Color) -> Self {
+        self.literal = literal;
+        self
+    }
+
+    /// builder function setting the colour of strings
+    pub fn and_string(mut self, string: Color) -> Self {
+        self.string = string;
+        self
+    }
+
+    /// builder function setting the colour of numbers
+    pub fn and_number(mut self, number: Color) -> Self {
+        self.number = number;
+        self
+    }
+
+    /// builder function setting the colour of comments
+    pub fn and_comment(mut self, comment: Color) -> Self {
+        self.comment = comment;
+        self
+    }
+
+    /// builder function setting the colour of function names
+    pub fn and_function(mut self, function: Color) -> Self {
+        self.function = function;
+        self
+    }
+
+    /// builder function setting the colour of plain tokens
+    pub fn and_plain(mut self, plain: Option<Color>) -> Self {
+        self.plain = plain;
+        self
+    }
+
+    /// colour a token of `kind` is drawn in
+    pub fn color(&self, kind: TokenKind) -> Option<Color> {
+        match kind {
+            TokenKind::Keyword => Some(self.keyword),
+            TokenKind::Type => Some(self.type_name),
+            TokenKind::Literal => Some(self.literal),
+            TokenKind::String => Some(self.string),
+            TokenKind::Number => Some(self.number),
+            TokenKind::Comment => Some(self.comment),
+            TokenKind::Function => Some(self.function),
+            TokenKind::Plain => self.plain,
+        }
+    }
+}
+
+impl Default for HighlightTheme {
+    fn default() -> Self {
+        HighlightTheme {
+            keyword: Color::new(207, 34, 46),
+            type_name: Color::new(149, 56, 0),
+            literal: Color::new(5, 80, 174),
+            string: Color::new(10, 48, 105),
+            number: Color::new(5, 80, 174),
+            comment: Color::new(110, 119, 129),
+            function: Color::new(130, 80, 223),
+            plain: None,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use hypher::Lang;
+
+/// Pattern based (Liang) hyphenation of the words in a text block, used by both `LineBreaking` modes
+///
+/// # Example
+/// ```
+/// # use edra::types::Hyphenation;
+/// let hyphenation = Hyphenation::new("en-GB")   // ISO 639-1 language code, region subtags are ignored
+///     .expect("no patterns for the language")
+///     .with_min_lengths(2, 3);                  // letters kept before and after a hyphen
+///
+/// assert_eq!(hyphenation.points("extensive"), vec![2, 5]);
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct Hyphenation {
+    language: Lang,
+    /// fewest letters left in front of a hyphen
+    pub left_min: usize,
+    /// fewest letters carried over to the next line
+    pub right_min: usize,
+}
+
+impl Hyphenation {
+    /// hyphenation for an ISO 639-1 language code (`"en"`, `"de"`, `"fr-CA"`...), `None` when no patterns exist for it
+    /// - the shortest syllables allowed at either end of a word follow the language's typographic conventions
+    pub fn new(language: &str) -> Option<Self> {
+        let code = language.get(..2)?.to_ascii_lowercase();
+        let code: [u8; 2] = code.as_bytes().try_into().ok()?;
+        let language = Lang::from_iso(code)?;
+        let (left_min, right_min) = language.bounds();
+
+        Some(Hyphenation { language, left_min, right_min })
+    }
+
+    /// hyphenation with the English (US) patterns
+    pub fn english() -> Self {
+        let (left_min, right_min) = Lang::English.bounds();
+
+        Hyphenation { language: Lang::English, left_min, right_min }
+    }
+
+    /// builder function setting the fewest letters kept on either side of a hyphen
+    pub fn with_min_lengths(mut self, left_min: usize, right_min: usize) -> Self {
+        self.left_min = left_min;
+        self.right_min = right_min;
+        self
+    }
+
+    /// byte offsets in `text` a hyphen may be inserted at, found separately in each run of letters
+    /// - words in capitals (acronyms) are never hyphenated
+    pub fn points(&self, text: &str) -> Vec<usize> {
+        let mut points: Vec<usize> = Vec::new();
+        let mut word_start: Option<usize> = None;
+
+        for (index, ch) in text.char_indices().chain([(text.len(), ' ')]) {
+            match (ch.is_alphabetic(), word_start) {
+                (true, None) => word_start = Some(index),
+                (false, Some(start)) => {
+                    self.word_points(&text[start..index], start, &mut points);
+                    word_start = None;
+                },
+                _ => {}
+            }
+        }
+
+        points
+    }
+
+    // collects the syllable boundaries of a single word
+    fn word_points(&self, word: &str, word_start: usize, points: &mut Vec<usize>) {
+        if !word.chars().any(char::is_lowercase) {
+            return;
+        }
+
+        let mut position = word_start;
+        let word_end = word_start + word.len();
+
+        for syllable in hypher::hyphenate_bounded(word, self.language, self.left_min, self.right_min) {
+            position += syllable.len();
+
+            if position < word_end {
+                points.push(position);
+            }
+        }
+    }
+}
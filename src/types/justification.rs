@@ -0,0 +1,112 @@
+use crate::types::TextAlignment;
+
+/// Settings of justified (`textAlign: justify`) text blocks, shared by every block in a `Doc`
+///
+/// # Example
+/// ```
+/// # use edra::types::{ Justification, JustifySpacing, TextAlignment };
+/// let justification = Justification::new()
+///     .with_last_line(TextAlignment::Center)     // TextAlignment of the last line
+///     .and_spacing(JustifySpacing::Operators)    // JustifySpacing
+///     .and_max_letter_spacing(0.05);             // f32, fraction of the font size
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct Justification {
+    /// alignment of the last line of a justified block, `TextAlignment::Justify` stretches it like the others
+    pub last_line: TextAlignment,
+    /// how the space left on a line is spread over it
+    pub spacing: JustifySpacing,
+    /// widest letter spacing, as a fraction of the font size, used on lines without word gaps before they are left ragged
+    pub max_letter_spacing: f32,
+}
+
+/// How a justified line is stretched to the margin
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub enum JustifySpacing {
+    /// each word is positioned on its own and the gaps between words are widened
+    #[default]
+    Positioned,
+    /// each line is drawn as continuous text with its spaces, widened with the word spacing (`Tw`) operator so text
+    /// extraction, selection and search see ordinary words
+    /// - embedded fonts (two byte codes, which `Tw` doesn't apply to) are widened with letter spacing (`Tc`) instead
+    Operators,
+}
+
+impl Justification {
+    /// default settings:
+    /// - Last line: left
+    /// - Spacing: positioned words
+    /// - Max letter spacing: 0.1 em
+    pub fn new() -> Self {
+        Justification::default()
+    }
+
+    /// builder function setting the alignment of the last line
+    /// - a line ended by a hard break is aligned like the last line, it ends its paragraph as the editor shows it
+    /// ```
+    /// # use edra::types::{ Doc, Justification, JustifySpacing, TextAlignment };
+    /// let mut doc = serde_json::from_str::<Doc>(r#"{ "type": "doc", "content": [
+    ///     { "type": "paragraph", "attrs": { "textAlign": "justify" }, "content": [
+    ///         { "type": "text", "text": "The quick brown fox jumps over the lazy dog while the committee deliberates at length about the extraordinarily incomprehensible characteristics of typographical conventions." },
+    ///         { "type": "hardBreak" },
+    ///         { "type": "text", "text": "Signed, the committee." }
+    ///     ] }
+    /// ] }"#)
+    ///     .expect("invalid json")
+    ///     .with_justification(Justification::new()
+    ///         .with_last_line(TextAlignment::Left)
+    ///         .and_spacing(JustifySpacing::Operators));
+    ///
+    /// let pdf_file = doc.render().expect("render failed");
+    /// let content = String::from_utf8_lossy(&pdf_file);
+    /// let spacings: Vec<&str> = content.lines().filter(|line| line.ends_with(" Tw")).collect();
+    ///
+    /// // only the first of the three lines is stretched, the line ended by the hard break is left alone
+    /// assert_eq!(spacings.len(), 2);
+    /// assert_ne!(spacings[0], "0 Tw");
+    /// assert_eq!(spacings[1], "0 Tw");
+    /// ```
+    pub fn with_last_line(mut self, last_line: TextAlignment) -> Self {
+        self.last_line = last_line;
+        self
+    }
+
+    /// builder function setting how lines are stretched
+    pub fn and_spacing(mut self, spacing: JustifySpacing) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// builder function setting the widest letter spacing, as a fraction of the font size
+    /// - letters are never squeezed together, a word too long for the line overflows it at its natural width
+    /// ```
+    /// # use edra::types::{ Doc, Justification, JustifySpacing };
+    /// // 115 letters are about 110 wider than the line, the justified first line would squeeze them by 1pt each
+    /// let word = "e".repeat(115);
+    /// let json = format!(r#"{{ "type": "doc", "content": [
+    ///     {{ "type": "paragraph", "attrs": {{ "textAlign": "justify" }}, "content": [{{ "type": "text", "text": "{word} end" }}] }}
+    /// ] }}"#);
+    /// let mut doc = serde_json::from_str::<Doc>(&json)
+    ///     .expect("invalid json")
+    ///     .with_justification(Justification::new().and_spacing(JustifySpacing::Operators).and_max_letter_spacing(0.1));
+    ///
+    /// let pdf_file = doc.render().expect("render failed");
+    /// let content = String::from_utf8_lossy(&pdf_file);
+    ///
+    /// assert!(!content.lines().any(|line| line.ends_with(" Tc") && line.starts_with('-')));
+    /// ```
+    pub fn and_max_letter_spacing(mut self, max_letter_spacing: f32) -> Self {
+        self.max_letter_spacing = max_letter_spacing;
+        self
+    }
+}
+
+impl Default for Justification {
+    fn default() -> Self {
+        Justification {
+            last_line: TextAlignment::Left,
+            spacing: JustifySpacing::Positioned,
+            max_letter_spacing: 0.1,
+        }
+    }
+}
@@ -0,0 +1,401 @@
+use crate::types::{ BreakItem, Totals };
+
+/// How the words of a text block are broken into lines
+///
+/// # Example
+/// ```
+/// # use edra::types::{ LineBreaking, TotalFit };
+/// let line_breaking = LineBreaking::TotalFit(
+///     TotalFit::new()
+///         .with_tolerance(3.0)       // f32, loosest adjustment ratio allowed
+///         .and_hyphen_penalty(100.0) // f32, cost of ending a line in a hyphen
+/// );
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub enum LineBreaking {
+    /// fills each line with as many words as fit, then moves on
+    #[default]
+    Greedy,
+    /// chooses the breaks of the whole paragraph together so its lines are as evenly spaced as possible (Knuth & Plass)
+    /// - falls back to greedy breaking when no set of breaks fits, even without a tolerance
+    TotalFit(TotalFit),
+}
+
+/// Settings of the Knuth & Plass total fit line breaker, defaults follow TeX
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct TotalFit {
+    /// largest adjustment ratio (stretch used over stretch available) a line may have
+    pub tolerance: f32,
+    /// added to the badness of every line, higher values favour fewer lines
+    pub line_penalty: f32,
+    /// cost of breaking at a hyphenation point
+    pub hyphen_penalty: f32,
+    /// demerits of two consecutive lines ending in a hyphen
+    pub consecutive_hyphen_demerits: f32,
+    /// demerits of a very loose line next to a tight one
+    pub fitness_demerits: f32,
+}
+
+impl LineBreaking {
+    /// cost of breaking at a hyphenation point, recorded on the penalty items
+    pub fn hyphen_penalty(&self) -> f32 {
+        match self {
+            LineBreaking::Greedy => TotalFit::default().hyphen_penalty,
+            LineBreaking::TotalFit(total_fit) => total_fit.hyphen_penalty,
+        }
+    }
+
+    /// indexes of the items each line ends at, the last one is the forced break ending the paragraph
+    /// - `line_stretch` is the flexibility of a ragged line end, zero for justified text whose glue does the stretching
+    pub fn breaks(&self, items: &[BreakItem], line_width: f32, line_stretch: f32) -> Vec<usize> {
+        match self {
+            LineBreaking::Greedy => LineBreaking::greedy(items, line_width),
+            LineBreaking::TotalFit(total_fit) => total_fit
+                .breaks(items, line_width, line_stretch, total_fit.tolerance)
+                // a final pass without a tolerance only fails on words wider than the line
+                .or_else(|| total_fit.breaks(items, line_width, line_stretch, f32::INFINITY))
+                .unwrap_or_else(|| LineBreaking::greedy(items, line_width)),
+        }
+    }
+
+    // breaks at the last opportunity that still fits, lines holding a single overlong word overflow
+    fn greedy(items: &[BreakItem], line_width: f32) -> Vec<usize> {
+        let totals = BreakItem::totals(items);
+        let line_width = line_width as f64;
+        let mut breaks: Vec<usize> = Vec::new();
+        let mut start = totals[BreakItem::line_start(items, 0)];
+        let mut candidate: Option<usize> = None;
+
+        for index in 0..items.len() {
+            if !BreakItem::is_legal_break(items, index) {
+                continue;
+            }
+
+            let width = |start: Totals| totals[index].width - start.width + items[index].break_width() as f64;
+
+            if width(start) > line_width {
+                if let Some(candidate) = candidate.take() {
+                    breaks.push(candidate);
+                    start = totals[BreakItem::line_start(items, candidate + 1)];
+                }
+            }
+
+            if items[index].is_forced() {
+                breaks.push(index);
+                start = totals[BreakItem::line_start(items, index + 1)];
+                candidate = None;
+            } else {
+                candidate = Some(index);
+            }
+        }
+
+        breaks
+    }
+}
+
+impl TotalFit {
+    /// default settings:
+    /// - Tolerance: 2.0
+    /// - Line penalty: 10
+    /// - Hyphen penalty: 50
+    /// - Consecutive hyphen demerits: 3000
+    /// - Fitness demerits: 100
+    pub fn new() -> Self {
+        TotalFit::default()
+    }
+
+    /// builder function setting the largest adjustment ratio a line may have
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// builder function setting the penalty added to every line
+    pub fn and_line_penalty(mut self, line_penalty: f32) -> Self {
+        self.line_penalty = line_penalty;
+        self
+    }
+
+    /// builder function setting the cost of breaking at a hyphenation point
+    pub fn and_hyphen_penalty(mut self, hyphen_penalty: f32) -> Self {
+        self.hyphen_penalty = hyphen_penalty;
+        self
+    }
+
+    /// builder function setting the demerits of consecutive hyphenated lines
+    pub fn and_consecutive_hyphen_demerits(mut self, consecutive_hyphen_demerits: f32) -> Self {
+        self.consecutive_hyphen_demerits = consecutive_hyphen_demerits;
+        self
+    }
+
+    /// builder function setting the demerits of a loose line next to a tight one
+    pub fn and_fitness_demerits(mut self, fitness_demerits: f32) -> Self {
+        self.fitness_demerits = fitness_demerits;
+        self
+    }
+
+    /// breaks with the fewest total demerits, `None` when no set of breaks keeps every line within `tolerance`
+    fn breaks(&self, items: &[BreakItem], line_width: f32, line_stretch: f32, tolerance: f32) -> Option<Vec<usize>> {
+        let totals = BreakItem::totals(items);
+        let line_width = line_width as f64;
+        let tolerance = tolerance as f64;
+
+        // every feasible break found so far, `active` holds those a later line can still start from
+        let mut nodes: Vec<Node> = vec![Node {
+            position: None,
+            fitness: 1,
+            demerits: 0.0,
+            previous: None,
+            start: totals[BreakItem::line_start(items, 0)]
+        }];
+        let mut active: Vec<usize> = vec![0];
+
+        for index in 0..items.len() {
+            if !BreakItem::is_legal_break(items, index) {
+                continue;
+            }
+
+            let item = &items[index];
+            let penalty = match item {
+                BreakItem::Penalty { penalty, .. } => *penalty as f64,
+                _ => 0.0
+            };
+
+            // best break from any active node, for each fitness class
+            let mut candidates: [Option<(f64, usize)>; 4] = [None; 4];
+            let mut position = 0;
+
+            while position < active.len() {
+                let node = &nodes[active[position]];
+                let ratio = self.adjustment_ratio(totals[index], node.start, item.break_width() as f64, line_width, line_stretch as f64);
+
+                // a forced break is taken however loose the line before it is (an empty line between hard breaks)
+                if (-1.0..=tolerance).contains(&ratio) || (ratio > tolerance && item.is_forced()) {
+                    let badness = (100.0 * ratio.abs().powi(3)).min(10000.0);
+                    let mut demerits = (self.line_penalty as f64 + badness).powi(2);
+
+                    if penalty >= 0.0 {
+                        demerits += penalty.powi(2);
+                    } else if penalty > f64::NEG_INFINITY {
+                        demerits -= penalty.powi(2);
+                    }
+
+                    let previous_flagged = node.position.is_some_and(|previous| items[previous].is_flagged());
+
+                    if item.is_flagged() && previous_flagged {
+                        demerits += self.consecutive_hyphen_demerits as f64;
+                    }
+
+                    let fitness = TotalFit::fitness(ratio);
+
+                    if fitness.abs_diff(node.fitness) > 1 {
+                        demerits += self.fitness_demerits as f64;
+                    }
+
+                    let total = node.demerits + demerits;
+
+                    if candidates[fitness].is_none_or(|(best, _)| total < best) {
+                        candidates[fitness] = Some((total, active[position]));
+                    }
+                }
+
+                // overfull lines only get worse, and no line can reach past a forced break
+                if ratio < -1.0 || item.is_forced() {
+                    active.remove(position);
+                } else {
+                    position += 1;
+                }
+            }
+
+            let start = totals[BreakItem::line_start(items, index + 1)];
+
+            for (fitness, candidate) in candidates.iter().enumerate() {
+                if let Some((demerits, previous)) = *candidate {
+                    nodes.push(Node { position: Some(index), fitness, demerits, previous: Some(previous), start });
+                    active.push(nodes.len() - 1);
+                }
+            }
+
+            if active.is_empty() {
+                return None;
+            }
+        }
+
+        let mut best = active
+            .iter()
+            .copied()
+            .min_by(|a, b| nodes[*a].demerits.total_cmp(&nodes[*b].demerits));
+        let mut breaks: Vec<usize> = Vec::new();
+
+        while let Some(node) = best {
+            if let Some(position) = nodes[node].position {
+                breaks.push(position);
+            }
+
+            best = nodes[node].previous;
+        }
+
+        breaks.reverse();
+
+        Some(breaks)
+    }
+
+    // how much of its stretch (positive) or shrink (negative) a line between `start` and `end` uses to fill `line_width`
+    fn adjustment_ratio(&self, end: Totals, start: Totals, break_width: f64, line_width: f64, line_stretch: f64) -> f64 {
+        let width = end.width - start.width + break_width;
+
+        if width < line_width {
+            let stretch = end.stretch - start.stretch + line_stretch;
+
+            match (end.fills > start.fills, stretch > 0.0) {
+                (true, _) => 0.0,
+                (false, true) => (line_width - width) / stretch,
+                (false, false) => f64::INFINITY
+            }
+        } else if width > line_width {
+            let shrink = end.shrink - start.shrink;
+
+            match shrink > 0.0 {
+                true => (line_width - width) / shrink,
+                false => f64::NEG_INFINITY
+            }
+        } else {
+            0.0
+        }
+    }
+
+    // tight, decent, loose and very loose lines
+    fn fitness(ratio: f64) -> usize {
+        match ratio {
+            ratio if ratio < -0.5 => 0,
+            ratio if ratio <= 0.5 => 1,
+            ratio if ratio <= 1.0 => 2,
+            _ => 3
+        }
+    }
+}
+
+impl Default for TotalFit {
+    fn default() -> Self {
+        TotalFit {
+            tolerance: 2.0,
+            line_penalty: 10.0,
+            hyphen_penalty: 50.0,
+            consecutive_hyphen_demerits: 3000.0,
+            fitness_demerits: 100.0,
+        }
+    }
+}
+
+// a feasible break, linked back to the break ending the line before it
+#[derive(Debug)]
+struct Node {
+    // item the line ends at, `None` for the start of the paragraph
+    position: Option<usize>,
+    fitness: usize,
+    demerits: f64,
+    previous: Option<usize>,
+    // totals at the first item of the line that follows
+    start: Totals,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{ BreakItem, LineBreaking, TotalFit };
+    use crate::types::break_item::test_items::{ boxed, glue, hyphen_penalty };
+
+    // boxes of the given widths separated by glue, ending the paragraph
+    fn paragraph(widths: &[f32]) -> Vec<BreakItem<'static>> {
+        let mut items: Vec<BreakItem> = Vec::new();
+
+        for (index, width) in widths.iter().enumerate() {
+            if index > 0 {
+                items.push(glue(10.0, 5.0, 3.0));
+            }
+
+            items.push(boxed(*width));
+        }
+
+        items.extend(BreakItem::forced_break());
+        items
+    }
+
+    #[test]
+    fn greedy_fills_each_line_before_breaking() {
+        let items = paragraph(&[30.0, 30.0, 30.0, 30.0]);
+
+        assert_eq!(LineBreaking::Greedy.breaks(&items, 70.0, 0.0), vec![3, 9]);
+    }
+
+    #[test]
+    fn forced_breaks_end_lines_that_would_fit() {
+        let mut items = paragraph(&[10.0]);
+        items.extend(paragraph(&[10.0]));
+
+        assert_eq!(LineBreaking::Greedy.breaks(&items, 100.0, 0.0), vec![3, 7]);
+        assert_eq!(LineBreaking::TotalFit(TotalFit::new()).breaks(&items, 100.0, 0.0), vec![3, 7]);
+    }
+
+    #[test]
+    fn boxes_wider_than_the_line_overflow_on_their_own_line() {
+        let items = paragraph(&[50.0, 50.0]);
+
+        assert_eq!(LineBreaking::Greedy.breaks(&items, 20.0, 0.0), vec![1, 5]);
+    }
+
+    #[test]
+    fn total_fit_falls_back_to_greedy_on_overfull_boxes() {
+        let items = paragraph(&[50.0, 50.0]);
+        let total_fit = TotalFit::new();
+
+        assert_eq!(total_fit.breaks(&items, 20.0, 0.0, total_fit.tolerance), None);
+        assert_eq!(total_fit.breaks(&items, 20.0, 0.0, f32::INFINITY), None);
+        assert_eq!(LineBreaking::TotalFit(total_fit).breaks(&items, 20.0, 0.0), vec![1, 5]);
+    }
+
+    #[test]
+    fn hyphens_count_towards_the_width_of_their_line() {
+        // "aaaa bb-cc": the hyphenated line is 45 wide, 40 without its hyphen
+        let mut items = vec![
+            boxed(20.0),
+            glue(10.0, 5.0, 3.0),
+            boxed(10.0),
+            hyphen_penalty(5.0),
+            boxed(10.0),
+        ];
+        items.extend(BreakItem::forced_break());
+
+        assert_eq!(LineBreaking::Greedy.breaks(&items, 42.0, 0.0), vec![1, 7]);
+        assert_eq!(LineBreaking::Greedy.breaks(&items, 46.0, 0.0), vec![3, 7]);
+
+        let totals = BreakItem::totals(&items);
+        let ratio = TotalFit::new().adjustment_ratio(totals[3], totals[0], items[3].break_width() as f64, 45.0, 0.0);
+
+        assert_eq!(ratio, 0.0);
+    }
+
+    #[test]
+    fn the_last_line_of_a_paragraph_is_not_stretched() {
+        let items = paragraph(&[30.0, 30.0, 10.0]);
+        let total_fit = TotalFit::new().with_tolerance(1.0);
+        let totals = BreakItem::totals(&items);
+
+        assert_eq!(total_fit.breaks(&items, 70.0, 0.0, total_fit.tolerance), Some(vec![3, 7]));
+        // the infinite glue of the forced break fills the 60 left over
+        assert_eq!(total_fit.adjustment_ratio(totals[7], totals[4], 0.0, 70.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn adjustment_ratio_uses_stretch_or_shrink() {
+        let items = paragraph(&[30.0, 30.0]);
+        let totals = BreakItem::totals(&items);
+        let total_fit = TotalFit::new();
+
+        // 70 wide with 5 stretch and 3 shrink
+        assert_eq!(total_fit.adjustment_ratio(totals[3], totals[0], 0.0, 80.0, 0.0), 2.0);
+        assert_eq!(total_fit.adjustment_ratio(totals[3], totals[0], 0.0, 67.0, 0.0), -1.0);
+        assert_eq!(total_fit.adjustment_ratio(totals[1], totals[0], 0.0, 40.0, 0.0), f64::INFINITY);
+        assert_eq!(total_fit.adjustment_ratio(totals[1], totals[0], 0.0, 40.0, 10.0), 1.0);
+        assert_eq!(total_fit.adjustment_ratio(totals[1], totals[0], 0.0, 20.0, 0.0), f64::NEG_INFINITY);
+    }
+}
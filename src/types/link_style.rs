@@ -0,0 +1,48 @@
+use crate::types::Color;
+
+/// Appearance of text carrying an Edra `link` mark, shared by every link in a `Doc`
+/// 
+/// # Example
+/// ```
+/// # use edra::types::{ Color, LinkStyle };
+/// let link_style = LinkStyle::new()
+///     .with_color(Some(Color::new(0, 102, 204))) // Option<Color>, `None` keeps the text colour
+///     .and_underline(false);                     // bool
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct LinkStyle {
+    /// fill colour of link text, a `textStyle` colour on the same run takes precedence
+    pub color: Option<Color>,
+    /// underlines link text
+    pub underline: bool,
+}
+
+impl LinkStyle {
+    /// default settings:
+    /// - Color: blue (#0000EE)
+    /// - Underline: true
+    pub fn new() -> Self {
+        LinkStyle::default()
+    }
+
+    /// builder function setting the colour of link text
+    pub fn with_color(mut self, color: Option<Color>) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// builder function setting whether link text is underlined
+    pub fn and_underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+}
+
+impl Default for LinkStyle {
+    fn default() -> Self {
+        LinkStyle {
+            color: Some(Color::new(0, 0, 238)),
+            underline: true,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+/// List settings shared by every ordered and bullet list in a `Doc`
+/// 
+/// # Example
+/// ```
+/// # use edra::types::ListStyle;
+/// let list_style = ListStyle::new()
+///     .with_bullets(vec!['•', '-', '*']) // Vec<char>, one per nesting level
+///     .and_indent(24.0);                  // f32
+/// ```
+#[derive(Debug,Clone,PartialEq)]
+pub struct ListStyle {
+    /// bullet character for each nesting level, deeper levels cycle back to the start
+    pub bullets: Vec<char>,
+    /// hanging indent added for each nesting level, the number or bullet is drawn inside it
+    pub indent: f32,
+}
+
+impl ListStyle {
+    /// default settings:
+    /// - Bullets: • – ·
+    /// - Indent: 24.0
+    pub fn new() -> Self {
+        ListStyle::default()
+    }
+
+    /// builder function setting the bullet characters, one per nesting level
+    pub fn with_bullets(mut self, bullets: Vec<char>) -> Self {
+        self.bullets = bullets;
+        self
+    }
+
+    /// builder function setting the indent of each nesting level
+    pub fn and_indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// returns the bullet for a zero based nesting level
+    pub fn bullet(&self, depth: usize) -> char {
+        if self.bullets.is_empty() {
+            return '•';
+        }
+
+        self.bullets[depth % self.bullets.len()]
+    }
+}
+
+impl Default for ListStyle {
+    fn default() -> Self {
+        ListStyle {
+            bullets: Vec::from(['•', '–', '·']),
+            indent: 24.0,
+        }
+    }
+}
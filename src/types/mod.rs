@@ -1,27 +1,77 @@
 mod attribute_field;
+mod break_item;
+mod code_style;
+mod color;
 mod content;
 mod page;
+mod pagination;
+mod page_setup;
+mod quote_style;
+mod registered_font;
+mod rule_style;
+mod script;
+mod render_error;
 mod doc;
+mod embedded_font;
+mod encoding;
 mod font;
 mod font_reference;
+mod font_size;
 mod font_style;
+mod font_variants;
 mod font_family;
+mod header_footer;
+mod heading_style;
+#[cfg(feature = "highlight")]
+mod highlight;
+mod highlight_theme;
+mod hyphenation;
+mod justification;
+mod line_breaking;
+mod link_style;
+mod list_style;
+mod standard_metrics;
 mod text;
 mod text_alignment;
+mod text_style;
+mod true_type_font;
 mod writer;
 
 pub use attribute_field::AttributeField;
+pub use break_item::{ BreakItem, Totals };
+pub use code_style::{ CodeOverflow, CodeStyle };
+pub use color::Color;
 pub use content::{ContentField, BlockType};
 pub use doc::Doc;
+pub use embedded_font::EmbeddedFont;
+pub use encoding::{ decompose_ligature, encode_win_ansi, win_ansi, win_ansi_char, win_ansi_substitute, FontEncoding };
 pub use font::Font;
 pub use font_reference::FontReference;
+pub use font_size::FontSize;
 pub use font_family::FontFamily;
-pub use page::{ Page, PageContent };
-pub use text::{ Line, TextBlock, Word };
+pub use font_variants::FontVariants;
+pub use header_footer::{ HeaderFooter, Slots };
+pub use heading_style::{ HeadingStyle, HeadingStyles };
+#[cfg(feature = "highlight")]
+pub use highlight::Language;
+pub use highlight_theme::{ HighlightTheme, TokenKind };
+pub use hyphenation::Hyphenation;
+pub use justification::{ Justification, JustifySpacing };
+pub use pagination::{ Fragment, Pagination };
+pub use line_breaking::{ LineBreaking, TotalFit };
+pub use link_style::LinkStyle;
+pub use list_style::ListStyle;
+pub use page::{ LinkAnnotation, Page, PageContent };
+pub use page_setup::{ Margins, Orientation, PageSetup, PaperSize };
+pub use registered_font::RegisteredFont;
+pub use rule_style::{ LineStyle, RuleStyle };
+pub use script::Script;
+pub use render_error::RenderError;
+pub use quote_style::{ QuoteRule, QuoteStyle };
+pub use text::{ Label, Line, Nesting, TextBlock, Word };
+pub use true_type_font::TrueTypeFont;
 pub use writer::Writer;
 
 pub use text_alignment::TextAlignment;
-pub use font_style::{
-    FontStyle,
-    Style
-};
\ No newline at end of file
+pub use text_style::{ FontSlant, FontWeight, TextStyle };
+pub use font_style::FontStyle;
@@ -1,13 +1,21 @@
-use pdf_writer::{ Content, Ref };
+use pdf_writer::{ Content, Rect, Ref };
 
 /// container for pdf_writer page references
 pub struct Page {
     pub page_id: Ref,
-    pub contents: Vec<PageContent>
+    pub contents: Vec<PageContent>,
+    pub links: Vec<LinkAnnotation>
 }
 
 /// each page gets a single `Content` object
 pub struct PageContent {
     pub content_id: Ref,
     pub content: Content
-}
\ No newline at end of file
+}
+
+/// clickable area over the part of a link run drawn on one line, opening `uri`
+pub struct LinkAnnotation {
+    pub id: Ref,
+    pub rect: Rect,
+    pub uri: String
+}
@@ -0,0 +1,127 @@
+/// Named paper sizes, dimensions are in points (1/72 inch) and always given in portrait orientation
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum PaperSize {
+    A3,
+    A4,
+    A5,
+    Letter,
+    Legal,
+    /// any other size, width and height in points
+    Custom { width: f32, height: f32 }
+}
+
+impl PaperSize {
+    /// returns (width, height) in points for a portrait page
+    pub fn dimensions(&self) -> (f32, f32) {
+        match *self {
+            PaperSize::A3 => (841.89, 1190.55),
+            PaperSize::A4 => (595.28, 841.89),
+            PaperSize::A5 => (419.53, 595.28),
+            PaperSize::Letter => (612.0, 792.0),
+            PaperSize::Legal => (612.0, 1008.0),
+            PaperSize::Custom { width, height } => (width, height)
+        }
+    }
+}
+
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum Orientation {
+    Portrait,
+    Landscape
+}
+
+/// distance from each edge of the page to the writeable area, in points
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct Margins {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Margins {
+    /// sets each margin individually, clockwise from the top like CSS
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Margins { top, right, bottom, left }
+    }
+
+    /// sets the same margin on all four sides
+    pub fn uniform(margin: f32) -> Self {
+        Margins::new(margin, margin, margin, margin)
+    }
+}
+
+/// Page settings for every page in a `Doc`
+/// 
+/// # Example
+/// ```
+/// # use edra::types::{ Margins, Orientation, PageSetup, PaperSize };
+/// let page_setup = PageSetup::new()
+///     .with_paper_size(PaperSize::Letter)  // PaperSize
+///     .and_orientation(Orientation::Landscape) // Orientation
+///     .and_margins(Margins::new(96.0, 48.0, 48.0, 48.0)); // Margins
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct PageSetup {
+    pub paper_size: PaperSize,
+    pub orientation: Orientation,
+    pub margins: Margins,
+}
+
+impl PageSetup {
+    /// default settings:
+    /// - Paper size: A4
+    /// - Orientation: Portrait
+    /// - Margins: 48.0 (1/2 inch) on all sides
+    pub fn new() -> Self {
+        PageSetup::default()
+    }
+
+    /// builder function setting the paper size
+    pub fn with_paper_size(mut self, paper_size: PaperSize) -> Self {
+        self.paper_size = paper_size;
+        self
+    }
+
+    /// builder function setting the page orientation
+    pub fn and_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// builder function setting the page margins
+    pub fn and_margins(mut self, margins: Margins) -> Self {
+        self.margins = margins;
+        self
+    }
+
+    /// page width in points after orientation is applied
+    pub fn width(&self) -> f32 {
+        let (width, height) = self.paper_size.dimensions();
+
+        match self.orientation {
+            Orientation::Portrait => width,
+            Orientation::Landscape => height
+        }
+    }
+
+    /// page height in points after orientation is applied
+    pub fn height(&self) -> f32 {
+        let (width, height) = self.paper_size.dimensions();
+
+        match self.orientation {
+            Orientation::Portrait => height,
+            Orientation::Landscape => width
+        }
+    }
+}
+
+impl Default for PageSetup {
+    fn default() -> Self {
+        PageSetup {
+            paper_size: PaperSize::A4,
+            orientation: Orientation::Portrait,
+            margins: Margins::uniform(48.0)
+        }
+    }
+}
@@ -0,0 +1,371 @@
+use std::ops::Range;
+
+use crate::types::{ BlockType, TextBlock };
+
+/// Rules deciding where text blocks are split across pages, shared by every block in a `Doc`
+///
+/// # Example
+/// ```
+/// # use edra::types::{ BlockType, Pagination };
+/// let pagination = Pagination::new()
+///     .with_orphans(3)                          // usize, lines left at the bottom of a page
+///     .and_widows(3)                            // usize, lines carried to the top of the next page
+///     .and_keep_headings_with_next(true)        // bool
+///     .and_keep_together(BlockType::ListItem);  // BlockType, never split across pages
+/// ```
+#[derive(Debug,Clone,PartialEq)]
+pub struct Pagination {
+    /// fewest lines of a block left at the bottom of a page, fewer move the whole block to the next page
+    pub orphans: usize,
+    /// fewest lines of a block carried over to the top of the next page
+    pub widows: usize,
+    /// headings never end a page, they move to the next page with the first lines of the block following them
+    pub keep_headings_with_next: bool,
+    /// blocks that are never split across pages unless they are taller than a page
+    /// - `BlockType::ListItem` keeps the text of each list item together
+    pub keep_together: Vec<BlockType>,
+}
+
+impl Pagination {
+    /// default settings:
+    /// - Orphans: 2
+    /// - Widows: 2
+    /// - Headings kept with the next block
+    /// - Headings kept together
+    pub fn new() -> Self {
+        Pagination::default()
+    }
+
+    /// builder function setting the fewest lines left at the bottom of a page
+    pub fn with_orphans(mut self, orphans: usize) -> Self {
+        self.orphans = orphans;
+        self
+    }
+
+    /// builder function setting the fewest lines carried over to the next page
+    pub fn and_widows(mut self, widows: usize) -> Self {
+        self.widows = widows;
+        self
+    }
+
+    /// builder function setting whether headings stay on the page of the block following them
+    pub fn and_keep_headings_with_next(mut self, keep_headings_with_next: bool) -> Self {
+        self.keep_headings_with_next = keep_headings_with_next;
+        self
+    }
+
+    /// builder function adding a block type that is never split across pages
+    pub fn and_keep_together(mut self, block_type: BlockType) -> Self {
+        if !self.keep_together.contains(&block_type) {
+            self.keep_together.push(block_type);
+        }
+
+        self
+    }
+
+    /// true when blocks of `block_type` are never split across pages
+    pub fn keeps_together(&self, block_type: BlockType) -> bool {
+        self.keep_together.contains(&block_type)
+    }
+
+    /// splits laid out blocks into the `Fragment` of each block written to each page, before anything is written
+    /// - `page_height` is the height between the top and bottom margins
+    /// - a block marked `keep_with_next` starts on the same page as the first lines of the block following it
+    pub fn paginate(&self, blocks: &[TextBlock], page_height: f32) -> Vec<Fragment> {
+        let mut fragments: Vec<Fragment> = Vec::with_capacity(blocks.len());
+        let mut page: usize = 0;
+        // height already taken up on the current page
+        let mut used: f32 = 0.0;
+        let mut group_start: usize = 0;
+
+        for (index, block) in blocks.iter().enumerate() {
+            // the last block has nothing to wait for
+            if block.keep_with_next && index + 1 < blocks.len() {
+                continue;
+            }
+
+            // blocks kept with this one lead its lines, their own spacing included
+            let group = &blocks[group_start..=index];
+            let mut heights: Vec<f32> = Vec::new();
+            let mut lead: usize = 0;
+            // first line of each block and the space added above it
+            let mut block_starts: Vec<(usize, f32)> = Vec::with_capacity(group.len());
+
+            for (offset, text_block) in group.iter().enumerate() {
+                let mut block_heights = text_block.line_heights();
+
+                // space above a block is dropped at the top of a page
+                if offset > 0 || used > 0.0 {
+                    if let Some(first) = block_heights.first_mut() {
+                        *first += text_block.pre_block_offset;
+                        block_starts.push((heights.len(), text_block.pre_block_offset));
+                    }
+                }
+
+                match offset + 1 == group.len() {
+                    true => lead = heights.len(),
+                    false => if let Some(last) = block_heights.last_mut() {
+                        *last += text_block.post_block_offset;
+                    }
+                }
+
+                heights.extend(block_heights);
+            }
+
+            let page_breaks = self.page_breaks(&heights, lead, block.keep_together, page_height - used, page_height);
+            let mut first: usize = 0;
+
+            for (offset, text_block) in group.iter().enumerate() {
+                let lines = first..first + text_block.lines.len();
+                let mut start = lines.start;
+
+                // each page break inside the block ends the lines written to the current page
+                for page_break in page_breaks.iter().copied().filter(|page_break| lines.contains(page_break)) {
+                    if page_break > start {
+                        fragments.push(Fragment { block: group_start + offset, lines: start - first..page_break - first, page });
+                    }
+
+                    page += 1;
+                    start = page_break;
+                }
+
+                fragments.push(Fragment { block: group_start + offset, lines: start - first..lines.end - first, page });
+                first = lines.end;
+            }
+
+            let page_start = page_breaks.last().copied();
+
+            used = match page_start {
+                // a block moved to the new page is written without the space above it
+                Some(page_start) => heights[page_start..].iter().sum::<f32>() - block_starts
+                    .iter()
+                    .find(|(line, _)| *line == page_start)
+                    .map_or(0.0, |(_, pre_block_offset)| *pre_block_offset),
+                None => used + heights.iter().sum::<f32>()
+            } + block.post_block_offset;
+
+            group_start = index + 1;
+        }
+
+        fragments
+    }
+
+    // indexes of the lines a new page is started before, for lines of `heights` starting with `room` left on the page
+    // - the first `lead` lines (kept headings) start on the same page as at least `orphans` lines after them
+    // - a split block leaves `orphans` lines behind and carries `widows` lines over
+    // - rules are dropped on a fresh page, where keeping lines together can't make them fit
+    fn page_breaks(&self, heights: &[f32], lead: usize, keep_together: bool, mut room: f32, page_height: f32) -> Vec<usize> {
+        let mut page_breaks: Vec<usize> = Vec::new();
+        let mut start: usize = 0;
+
+        loop {
+            let mut fit: usize = 0;
+            let mut used: f32 = 0.0;
+
+            while start + fit < heights.len() && used + heights[start + fit] <= room {
+                used += heights[start + fit];
+                fit += 1;
+            }
+
+            if start + fit == heights.len() {
+                break;
+            }
+
+            // a line taller than the page still has to go somewhere
+            let natural = fit.max(1);
+            let orphans = lead + self.orphans;
+
+            if start == 0 && (keep_together || fit < orphans) {
+                fit = 0;
+            }
+
+            let carried = heights.len() - start - fit;
+
+            if fit > 0 && carried < self.widows {
+                fit = fit.saturating_sub(self.widows - carried);
+
+                if start == 0 && fit < orphans {
+                    fit = 0;
+                }
+            }
+
+            if fit == 0 && (start > 0 || room >= page_height) {
+                fit = natural;
+            }
+
+            start += fit;
+            room = page_height;
+            page_breaks.push(start);
+        }
+
+        page_breaks
+    }
+}
+
+/// Lines of a laid out `TextBlock` that land on one page, found by `Pagination::paginate()`
+///
+/// # Example
+/// ```
+/// # use edra::types::{ Fragment, Pagination, TextBlock };
+/// let mut text_block = TextBlock::new().with_font_size(10.0);
+///
+/// for _ in 0..4 {
+///     text_block.next();
+/// }
+///
+/// // five empty lines of 15pt on pages with room for three
+/// let fragments = Pagination::new().paginate(&[text_block], 45.0);
+///
+/// assert_eq!(fragments, vec![
+///     Fragment { block: 0, lines: 0..3, page: 0 },
+///     Fragment { block: 0, lines: 3..5, page: 1 },
+/// ]);
+/// ```
+#[derive(Debug,Clone,PartialEq)]
+pub struct Fragment {
+    /// index of the block in the laid out blocks
+    pub block: usize,
+    /// lines of the block written to the page
+    pub lines: Range<usize>,
+    /// zero based index of the page
+    pub page: usize,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Pagination {
+            orphans: 2,
+            widows: 2,
+            keep_headings_with_next: true,
+            keep_together: Vec::from([BlockType::Heading]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{ Fragment, Pagination, TextBlock };
+
+    // a block of empty lines, 15 high each
+    fn block(lines: usize) -> TextBlock<'static> {
+        let mut text_block = TextBlock::new().with_font_size(10.0);
+
+        for _ in 1..lines {
+            text_block.next();
+        }
+
+        text_block
+    }
+
+    fn pages(fragments: &[Fragment]) -> Vec<(usize, usize, usize)> {
+        fragments.iter().map(|fragment| (fragment.block, fragment.lines.len(), fragment.page)).collect()
+    }
+
+    #[test]
+    fn blocks_that_fit_are_not_broken() {
+        assert!(Pagination::new().page_breaks(&[10.0; 5], 0, false, 50.0, 100.0).is_empty());
+    }
+
+    #[test]
+    fn too_few_lines_left_behind_move_the_block() {
+        let pagination = Pagination::new();
+
+        // one line fits, two orphans are needed
+        assert_eq!(pagination.page_breaks(&[10.0; 6], 0, false, 10.0, 100.0), vec![0]);
+        assert_eq!(pagination.page_breaks(&[10.0; 6], 0, false, 20.0, 100.0), vec![2]);
+        assert_eq!(pagination.with_orphans(3).page_breaks(&[10.0; 6], 0, false, 20.0, 100.0), vec![0]);
+    }
+
+    #[test]
+    fn too_few_lines_carried_over_pull_lines_down() {
+        let pagination = Pagination::new();
+
+        // four lines fit, leaving a single widow
+        assert_eq!(pagination.page_breaks(&[10.0; 5], 0, false, 40.0, 100.0), vec![3]);
+        assert_eq!(pagination.and_widows(1).page_breaks(&[10.0; 5], 0, false, 40.0, 100.0), vec![4]);
+    }
+
+    #[test]
+    fn widows_that_leave_too_few_orphans_move_the_block() {
+        // two lines fit, pulling one down for the widow leaves one orphan
+        assert_eq!(Pagination::new().page_breaks(&[10.0; 3], 0, false, 20.0, 100.0), vec![0]);
+    }
+
+    #[test]
+    fn leading_lines_need_orphans_after_them() {
+        let pagination = Pagination::new();
+
+        // a heading followed by five lines needs room for three
+        assert_eq!(pagination.page_breaks(&[10.0; 6], 1, false, 20.0, 100.0), vec![0]);
+        assert_eq!(pagination.page_breaks(&[10.0; 6], 1, false, 30.0, 100.0), vec![3]);
+    }
+
+    #[test]
+    fn blocks_kept_together_move_whole() {
+        assert_eq!(Pagination::new().page_breaks(&[10.0; 4], 0, true, 30.0, 100.0), vec![0]);
+    }
+
+    #[test]
+    fn blocks_taller_than_a_page_are_split_anyway() {
+        let pagination = Pagination::new();
+
+        // on a fresh page keeping fifteen lines together can't make them fit
+        assert_eq!(pagination.page_breaks(&[10.0; 15], 0, true, 100.0, 100.0), vec![10]);
+        // part way down a page they move to a fresh page first
+        assert_eq!(pagination.page_breaks(&[10.0; 15], 0, true, 30.0, 100.0), vec![0, 10]);
+        // a single line taller than the page still takes a page of its own
+        assert_eq!(pagination.page_breaks(&[150.0, 10.0], 0, false, 100.0, 100.0), vec![1]);
+    }
+
+    #[test]
+    fn headings_move_with_the_block_following_them() {
+        let blocks = [block(5), block(1).and_keep_with_next(true), block(4)];
+
+        // 25 is left below the first block, enough for the heading and a single line
+        assert_eq!(pages(&Pagination::new().paginate(&blocks, 100.0)), vec![(0, 5, 0), (1, 1, 1), (2, 4, 1)]);
+    }
+
+    #[test]
+    fn space_after_a_block_is_carried_to_the_next() {
+        let blocks = |post_block_offset: f32| [block(3).and_post_block_offset(post_block_offset), block(3)];
+
+        assert_eq!(pages(&Pagination::new().paginate(&blocks(10.0), 100.0)), vec![(0, 3, 0), (1, 3, 0)]);
+        assert_eq!(pages(&Pagination::new().paginate(&blocks(11.0), 100.0)), vec![(0, 3, 0), (1, 3, 1)]);
+    }
+
+    #[test]
+    fn space_after_each_block_of_a_group_is_carried() {
+        // heading 15 + 5, body 30 + 20 leave 70 used before the last block
+        let blocks = [
+            block(1).and_keep_with_next(true).and_post_block_offset(5.0),
+            block(2).and_post_block_offset(20.0),
+            block(3),
+        ];
+
+        assert_eq!(pages(&Pagination::new().paginate(&blocks, 115.0)), vec![(0, 1, 0), (1, 2, 0), (2, 3, 0)]);
+        assert_eq!(pages(&Pagination::new().paginate(&blocks, 114.0)), vec![(0, 1, 0), (1, 2, 0), (2, 3, 1)]);
+    }
+
+    #[test]
+    fn lines_carried_over_take_up_the_next_page() {
+        // six of eight lines fit, the two carried over leave 70 for the next block
+        let blocks = [block(8), block(5)];
+
+        assert_eq!(
+            pages(&Pagination::new().paginate(&blocks, 100.0)),
+            vec![(0, 6, 0), (0, 2, 1), (1, 3, 1), (1, 2, 2)]
+        );
+    }
+
+    #[test]
+    fn space_before_a_block_is_dropped_at_the_top_of_a_page() {
+        // 10 below the first block is too little for the second, which starts the next page without its 20 above
+        let blocks = [block(6), block(2).and_pre_block_offset(20.0), block(4).and_pre_block_offset(10.0)];
+
+        assert_eq!(pages(&Pagination::new().paginate(&blocks, 100.0)), vec![(0, 6, 0), (1, 2, 1), (2, 4, 1)]);
+        assert_eq!(
+            pages(&Pagination::new().paginate(&blocks, 99.0)),
+            vec![(0, 6, 0), (1, 2, 1), (2, 2, 1), (2, 2, 2)]
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use crate::types::Color;
+
+/// Blockquote settings shared by every `blockquote` in a `Doc`
+///
+/// # Example
+/// ```
+/// # use edra::types::{ Color, QuoteStyle };
+/// let quote_style = QuoteStyle::new()
+///     .with_indent(24.0)                        // f32
+///     .and_rule_color(Color::new(0, 102, 204))  // Color
+///     .and_rule_width(2.0)                      // f32
+///     .and_italic(true);                        // bool
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct QuoteStyle {
+    /// indent added for each level of quoting, the rule is drawn inside it
+    pub indent: f32,
+    pub rule_color: Color,
+    /// line width of the rule, zero leaves the quote without one
+    pub rule_width: f32,
+    /// sets quoted text in the italic variant
+    pub italic: bool,
+}
+
+impl QuoteStyle {
+    /// default settings:
+    /// - Indent: 18.0
+    /// - Rule: 3.0 wide, light grey
+    /// - Upright text
+    pub fn new() -> Self {
+        QuoteStyle::default()
+    }
+
+    /// builder function setting the indent of each quoting level
+    pub fn with_indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// builder function setting the colour of the rule
+    pub fn and_rule_color(mut self, rule_color: Color) -> Self {
+        self.rule_color = rule_color;
+        self
+    }
+
+    /// builder function setting the line width of the rule
+    pub fn and_rule_width(mut self, rule_width: f32) -> Self {
+        self.rule_width = rule_width;
+        self
+    }
+
+    /// builder function setting whether quoted text is italic
+    pub fn and_italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+}
+
+impl Default for QuoteStyle {
+    fn default() -> Self {
+        QuoteStyle {
+            indent: 18.0,
+            rule_color: Color::new(204, 204, 204),
+            rule_width: 3.0,
+            italic: false,
+        }
+    }
+}
+
+/// vertical rule drawn to the left of the lines of a quoted `TextBlock`, one for each quote it is nested in
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct QuoteRule {
+    /// distance of the rule's left edge from the left margin
+    pub x: f32,
+    pub width: f32,
+    pub color: Color,
+}
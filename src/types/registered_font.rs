@@ -0,0 +1,21 @@
+use std::fmt;
+use crate::traits::FontType;
+use crate::types::FontVariants;
+
+/// A font family registered with `Doc::register_font()`, selected with `FontFamily::Registered(name)`.
+/// The fonts are referenced by name and not embedded, the PDF viewer supplies (or substitutes) them.
+pub struct RegisteredFont {
+    pub name: &'static str,
+    /// character widths used for line breaking and the `Widths` array of each font
+    pub widths: Box<dyn FontType + Send + Sync>,
+    pub variants: FontVariants,
+}
+
+impl fmt::Debug for RegisteredFont {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegisteredFont")
+            .field("name", &self.name)
+            .field("variants", &self.variants)
+            .finish_non_exhaustive()
+    }
+}
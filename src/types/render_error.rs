@@ -0,0 +1,51 @@
+use std::fmt;
+use derive_more::From;
+use crate::types::BlockType;
+
+/// Returned by `Doc::render()` and `Doc::render_to()` when a document can't be turned into a PDF
+#[derive(Debug,From)]
+pub enum RenderError {
+    /// a font label was requested that was never registered with the `Writer`
+    #[from(ignore)]
+    MissingFont(String),
+    /// a TrueType/OpenType font file could not be parsed
+    #[from(ignore)]
+    InvalidFont(String),
+    /// a node was found in a position the render engine doesn't know how to draw
+    #[from(ignore)]
+    UnsupportedNode(BlockType),
+    /// the `Writer` has no `Page` or `PageContent` to write to
+    #[from(ignore)]
+    MissingPage,
+    /// the layout engine positioned text outside of the page's media box
+    #[from(ignore)]
+    OutOfBounds { x: f32, y: f32 },
+    /// the `date_format` of a header or footer is not a valid chrono format string
+    #[from(ignore)]
+    InvalidDateFormat(String),
+    /// the finished PDF could not be written to the output destination
+    Io(std::io::Error),
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::MissingFont(label) => write!(f, "font `{label}` is not registered"),
+            RenderError::InvalidFont(reason) => write!(f, "invalid font file: {reason}"),
+            RenderError::UnsupportedNode(block_type) => write!(f, "unsupported node `{block_type:?}`"),
+            RenderError::MissingPage => write!(f, "no page available to write to"),
+            RenderError::OutOfBounds { x, y } => write!(f, "text positioned outside of the page at ({x}, {y})"),
+            RenderError::InvalidDateFormat(format) => write!(f, "invalid date format `{format}`"),
+            RenderError::Io(e) => write!(f, "failed to write pdf: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for RenderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RenderError::Io(e) => Some(e),
+            _ => None
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::types::Color;
+
+/// Stroke of a horizontal rule
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Default)]
+pub enum LineStyle {
+    #[default]
+    Solid,
+    /// dashes three times as long as the rule is thick
+    Dashed,
+    /// round dots as wide as the rule is thick
+    Dotted,
+}
+
+/// Horizontal rule settings shared by every `horizontalRule` in a `Doc`
+///
+/// # Example
+/// ```
+/// # use edra::types::{ Color, LineStyle, RuleStyle };
+/// let rule_style = RuleStyle::new()
+///     .with_thickness(2.0)                   // f32
+///     .and_color(Color::new(0, 102, 204))    // Color
+///     .and_line_style(LineStyle::Dashed)     // LineStyle
+///     .and_space_before(18.0)                // f32
+///     .and_space_after(18.0);                // f32
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct RuleStyle {
+    pub thickness: f32,
+    pub color: Color,
+    pub line_style: LineStyle,
+    /// space above the rule, dropped at the top of a page
+    pub space_before: f32,
+    /// space between the rule and the block following it
+    pub space_after: f32,
+}
+
+impl RuleStyle {
+    /// default settings:
+    /// - Thickness: 1.0
+    /// - Colour: grey
+    /// - Solid line
+    /// - Space before & after: 12.0
+    pub fn new() -> Self {
+        RuleStyle::default()
+    }
+
+    /// builder function setting the line width of the rule
+    pub fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// builder function setting the colour of the rule
+    pub fn and_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// builder function setting whether the rule is solid, dashed or dotted
+    pub fn and_line_style(mut self, line_style: LineStyle) -> Self {
+        self.line_style = line_style;
+        self
+    }
+
+    /// builder function setting the space above the rule
+    pub fn and_space_before(mut self, space_before: f32) -> Self {
+        self.space_before = space_before;
+        self
+    }
+
+    /// builder function setting the space below the rule
+    pub fn and_space_after(mut self, space_after: f32) -> Self {
+        self.space_after = space_after;
+        self
+    }
+}
+
+impl Default for RuleStyle {
+    fn default() -> Self {
+        RuleStyle {
+            thickness: 1.0,
+            color: Color::new(153, 153, 153),
+            line_style: LineStyle::Solid,
+            space_before: 12.0,
+            space_after: 12.0,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+/// Vertical position of a text run, from Edra's `superscript` and `subscript` marks
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub enum Script {
+    #[default]
+    Baseline,
+    Superscript,
+    Subscript,
+}
+
+impl Script {
+    /// size a run set at `font_size` is drawn and measured at
+    pub fn font_size(&self, font_size: f32) -> f32 {
+        match self {
+            Script::Baseline => font_size,
+            Script::Superscript | Script::Subscript => font_size * 0.65,
+        }
+    }
+
+    /// text rise of a run set at `font_size`, positive values raise it above the baseline
+    pub fn rise(&self, font_size: f32) -> f32 {
+        match self {
+            Script::Baseline => 0.0,
+            Script::Superscript => font_size * 0.35,
+            Script::Subscript => font_size * -0.15,
+        }
+    }
+}
@@ -0,0 +1,324 @@
+// Glyph widths of the standard 14 fonts in 1/1000 em, indexed by character code starting at 0x20 (space).
+// Text fonts are indexed by their WinAnsiEncoding code, Symbol and ZapfDingbats by their built-in encoding.
+// Codes without a glyph have a width of 0.
+//
+// Generated from the Adobe Core 14 AFM files, which carry the following notices:
+// - Copyright (c) 1985, 1987, 1989, 1990, 1993, 1997 Adobe Systems Incorporated. All Rights Reserved.
+//   Times is a trademark of Linotype-Hell AG and/or its subsidiaries.
+// - Copyright (c) 1985, 1987, 1989, 1990, 1997 Adobe Systems Incorporated. All Rights Reserved.
+//   Helvetica is a trademark of Linotype-Hell AG and/or its subsidiaries.
+// - Copyright (c) 1989, 1990, 1991, 1992, 1993, 1997 Adobe Systems Incorporated. All Rights Reserved. (Courier)
+// - Copyright (c) 1985, 1987, 1989, 1990, 1997 Adobe Systems Incorporated. All rights reserved. (Symbol)
+// - Copyright (c) 1985, 1987, 1988, 1989, 1997 Adobe Systems Incorporated. All Rights Reserved.
+//   ITC Zapf Dingbats is a registered trademark of International Typeface Corporation.
+// Only the advance widths are reproduced here, unmodified.
+
+/// Times-Roman
+pub(crate) const TIMES_ROMAN: [u16; 224] = [
+     250,  333,  408,  500,  500,  833,  778,  180,  333,  333,  500,  564,  250,  333,  250,  278, // 0x20
+     500,  500,  500,  500,  500,  500,  500,  500,  500,  500,  278,  278,  564,  564,  564,  444, // 0x30
+     921,  722,  667,  667,  722,  611,  556,  722,  722,  333,  389,  722,  611,  889,  722,  722, // 0x40
+     556,  722,  667,  556,  611,  722,  722,  944,  722,  722,  611,  333,  278,  333,  469,  500, // 0x50
+     333,  444,  500,  444,  500,  444,  333,  500,  500,  278,  278,  500,  278,  778,  500,  500, // 0x60
+     500,  500,  333,  389,  278,  500,  500,  722,  500,  500,  444,  480,  200,  480,  541,    0, // 0x70
+     500,    0,  333,  500,  444, 1000,  500,  500,  333, 1000,  556,  333,  889,    0,  611,    0, // 0x80
+       0,  333,  333,  444,  444,  350,  500, 1000,  333,  980,  389,  333,  722,    0,  444,  722, // 0x90
+     250,  333,  500,  500,  500,  500,  200,  500,  333,  760,  276,  500,  564,  333,  760,  333, // 0xA0
+     400,  564,  300,  300,  333,  500,  453,  250,  333,  300,  310,  500,  750,  750,  750,  444, // 0xB0
+     722,  722,  722,  722,  722,  722,  889,  667,  611,  611,  611,  611,  333,  333,  333,  333, // 0xC0
+     722,  722,  722,  722,  722,  722,  722,  564,  722,  722,  722,  722,  722,  722,  556,  500, // 0xD0
+     444,  444,  444,  444,  444,  444,  667,  444,  444,  444,  444,  444,  278,  278,  278,  278, // 0xE0
+     500,  500,  500,  500,  500,  500,  500,  564,  500,  500,  500,  500,  500,  500,  500,  500, // 0xF0
+];
+
+/// Times-Bold
+pub(crate) const TIMES_BOLD: [u16; 224] = [
+     250,  333,  555,  500,  500, 1000,  833,  278,  333,  333,  500,  570,  250,  333,  250,  278, // 0x20
+     500,  500,  500,  500,  500,  500,  500,  500,  500,  500,  333,  333,  570,  570,  570,  500, // 0x30
+     930,  722,  667,  722,  722,  667,  611,  778,  778,  389,  500,  778,  667,  944,  722,  778, // 0x40
+     611,  778,  722,  556,  667,  722,  722, 1000,  722,  722,  667,  333,  278,  333,  581,  500, // 0x50
+     333,  500,  556,  444,  556,  444,  333,  500,  556,  278,  333,  556,  278,  833,  556,  500, // 0x60
+     556,  556,  444,  389,  333,  556,  500,  722,  500,  500,  444,  394,  220,  394,  520,    0, // 0x70
+     500,    0,  333,  500,  500, 1000,  500,  500,  333, 1000,  556,  333, 1000,    0,  667,    0, // 0x80
+       0,  333,  333,  500,  500,  350,  500, 1000,  333, 1000,  389,  333,  722,    0,  444,  722, // 0x90
+     250,  333,  500,  500,  500,  500,  220,  500,  333,  747,  300,  500,  570,  333,  747,  333, // 0xA0
+     400,  570,  300,  300,  333,  556,  540,  250,  333,  300,  330,  500,  750,  750,  750,  500, // 0xB0
+     722,  722,  722,  722,  722,  722, 1000,  722,  667,  667,  667,  667,  389,  389,  389,  389, // 0xC0
+     722,  722,  778,  778,  778,  778,  778,  570,  778,  722,  722,  722,  722,  722,  611,  556, // 0xD0
+     500,  500,  500,  500,  500,  500,  722,  444,  444,  444,  444,  444,  278,  278,  278,  278, // 0xE0
+     500,  556,  500,  500,  500,  500,  500,  570,  500,  556,  556,  556,  556,  500,  556,  500, // 0xF0
+];
+
+/// Times-Italic
+pub(crate) const TIMES_ITALIC: [u16; 224] = [
+     250,  333,  420,  500,  500,  833,  778,  214,  333,  333,  500,  675,  250,  333,  250,  278, // 0x20
+     500,  500,  500,  500,  500,  500,  500,  500,  500,  500,  333,  333,  675,  675,  675,  500, // 0x30
+     920,  611,  611,  667,  722,  611,  611,  722,  722,  333,  444,  667,  556,  833,  667,  722, // 0x40
+     611,  722,  611,  500,  556,  722,  611,  833,  611,  556,  556,  389,  278,  389,  422,  500, // 0x50
+     333,  500,  500,  444,  500,  444,  278,  500,  500,  278,  278,  444,  278,  722,  500,  500, // 0x60
+     500,  500,  389,  389,  278,  500,  444,  667,  444,  444,  389,  400,  275,  400,  541,    0, // 0x70
+     500,    0,  333,  500,  556,  889,  500,  500,  333, 1000,  500,  333,  944,    0,  556,    0, // 0x80
+       0,  333,  333,  556,  556,  350,  500,  889,  333,  980,  389,  333,  667,    0,  389,  556, // 0x90
+     250,  389,  500,  500,  500,  500,  275,  500,  333,  760,  276,  500,  675,  333,  760,  333, // 0xA0
+     400,  675,  300,  300,  333,  500,  523,  250,  333,  300,  310,  500,  750,  750,  750,  500, // 0xB0
+     611,  611,  611,  611,  611,  611,  889,  667,  611,  611,  611,  611,  333,  333,  333,  333, // 0xC0
+     722,  667,  722,  722,  722,  722,  722,  675,  722,  722,  722,  722,  722,  556,  611,  500, // 0xD0
+     500,  500,  500,  500,  500,  500,  667,  444,  444,  444,  444,  444,  278,  278,  278,  278, // 0xE0
+     500,  500,  500,  500,  500,  500,  500,  675,  500,  500,  500,  500,  500,  444,  500,  444, // 0xF0
+];
+
+/// Times-BoldItalic
+pub(crate) const TIMES_BOLD_ITALIC: [u16; 224] = [
+     250,  389,  555,  500,  500,  833,  778,  278,  333,  333,  500,  570,  250,  333,  250,  278, // 0x20
+     500,  500,  500,  500,  500,  500,  500,  500,  500,  500,  333,  333,  570,  570,  570,  500, // 0x30
+     832,  667,  667,  667,  722,  667,  667,  722,  778,  389,  500,  667,  611,  889,  722,  722, // 0x40
+     611,  722,  667,  556,  611,  722,  667,  889,  667,  611,  611,  333,  278,  333,  570,  500, // 0x50
+     333,  500,  500,  444,  500,  444,  333,  500,  556,  278,  278,  500,  278,  778,  556,  500, // 0x60
+     500,  500,  389,  389,  278,  556,  444,  667,  500,  444,  389,  348,  220,  348,  570,    0, // 0x70
+     500,    0,  333,  500,  500, 1000,  500,  500,  333, 1000,  556,  333,  944,    0,  611,    0, // 0x80
+       0,  333,  333,  500,  500,  350,  500, 1000,  333, 1000,  389,  333,  722,    0,  389,  611, // 0x90
+     250,  389,  500,  500,  500,  500,  220,  500,  333,  747,  266,  500,  606,  333,  747,  333, // 0xA0
+     400,  570,  300,  300,  333,  576,  500,  250,  333,  300,  300,  500,  750,  750,  750,  500, // 0xB0
+     667,  667,  667,  667,  667,  667,  944,  667,  667,  667,  667,  667,  389,  389,  389,  389, // 0xC0
+     722,  722,  722,  722,  722,  722,  722,  570,  722,  722,  722,  722,  722,  611,  611,  500, // 0xD0
+     500,  500,  500,  500,  500,  500,  722,  444,  444,  444,  444,  444,  278,  278,  278,  278, // 0xE0
+     500,  556,  500,  500,  500,  500,  500,  570,  500,  556,  556,  556,  556,  444,  500,  444, // 0xF0
+];
+
+/// Helvetica
+pub(crate) const HELVETICA: [u16; 224] = [
+     278,  278,  355,  556,  556,  889,  667,  191,  333,  333,  389,  584,  278,  333,  278,  278, // 0x20
+     556,  556,  556,  556,  556,  556,  556,  556,  556,  556,  278,  278,  584,  584,  584,  556, // 0x30
+    1015,  667,  667,  722,  722,  667,  611,  778,  722,  278,  500,  667,  556,  833,  722,  778, // 0x40
+     667,  778,  722,  667,  611,  722,  667,  944,  667,  667,  611,  278,  278,  278,  469,  556, // 0x50
+     333,  556,  556,  500,  556,  556,  278,  556,  556,  222,  222,  500,  222,  833,  556,  556, // 0x60
+     556,  556,  333,  500,  278,  556,  500,  722,  500,  500,  500,  334,  260,  334,  584,    0, // 0x70
+     556,    0,  222,  556,  333, 1000,  556,  556,  333, 1000,  667,  333, 1000,    0,  611,    0, // 0x80
+       0,  222,  222,  333,  333,  350,  556, 1000,  333, 1000,  500,  333,  944,    0,  500,  667, // 0x90
+     278,  333,  556,  556,  556,  556,  260,  556,  333,  737,  370,  556,  584,  333,  737,  333, // 0xA0
+     400,  584,  333,  333,  333,  556,  537,  278,  333,  333,  365,  556,  834,  834,  834,  611, // 0xB0
+     667,  667,  667,  667,  667,  667, 1000,  722,  667,  667,  667,  667,  278,  278,  278,  278, // 0xC0
+     722,  722,  778,  778,  778,  778,  778,  584,  778,  722,  722,  722,  722,  667,  667,  611, // 0xD0
+     556,  556,  556,  556,  556,  556,  889,  500,  556,  556,  556,  556,  278,  278,  278,  278, // 0xE0
+     556,  556,  556,  556,  556,  556,  556,  584,  611,  556,  556,  556,  556,  500,  556,  500, // 0xF0
+];
+
+/// Helvetica-Bold
+pub(crate) const HELVETICA_BOLD: [u16; 224] = [
+     278,  333,  474,  556,  556,  889,  722,  238,  333,  333,  389,  584,  278,  333,  278,  278, // 0x20
+     556,  556,  556,  556,  556,  556,  556,  556,  556,  556,  333,  333,  584,  584,  584,  611, // 0x30
+     975,  722,  722,  722,  722,  667,  611,  778,  722,  278,  556,  722,  611,  833,  722,  778, // 0x40
+     667,  778,  722,  667,  611,  722,  667,  944,  667,  667,  611,  333,  278,  333,  584,  556, // 0x50
+     333,  556,  611,  556,  611,  556,  333,  611,  611,  278,  278,  556,  278,  889,  611,  611, // 0x60
+     611,  611,  389,  556,  333,  611,  556,  778,  556,  556,  500,  389,  280,  389,  584,    0, // 0x70
+     556,    0,  278,  556,  500, 1000,  556,  556,  333, 1000,  667,  333, 1000,    0,  611,    0, // 0x80
+       0,  278,  278,  500,  500,  350,  556, 1000,  333, 1000,  556,  333,  944,    0,  500,  667, // 0x90
+     278,  333,  556,  556,  556,  556,  280,  556,  333,  737,  370,  556,  584,  333,  737,  333, // 0xA0
+     400,  584,  333,  333,  333,  611,  556,  278,  333,  333,  365,  556,  834,  834,  834,  611, // 0xB0
+     722,  722,  722,  722,  722,  722, 1000,  722,  667,  667,  667,  667,  278,  278,  278,  278, // 0xC0
+     722,  722,  778,  778,  778,  778,  778,  584,  778,  722,  722,  722,  722,  667,  667,  611, // 0xD0
+     556,  556,  556,  556,  556,  556,  889,  556,  556,  556,  556,  556,  278,  278,  278,  278, // 0xE0
+     611,  611,  611,  611,  611,  611,  611,  584,  611,  611,  611,  611,  611,  556,  611,  556, // 0xF0
+];
+
+/// Helvetica-Oblique
+pub(crate) const HELVETICA_OBLIQUE: [u16; 224] = [
+     278,  278,  355,  556,  556,  889,  667,  191,  333,  333,  389,  584,  278,  333,  278,  278, // 0x20
+     556,  556,  556,  556,  556,  556,  556,  556,  556,  556,  278,  278,  584,  584,  584,  556, // 0x30
+    1015,  667,  667,  722,  722,  667,  611,  778,  722,  278,  500,  667,  556,  833,  722,  778, // 0x40
+     667,  778,  722,  667,  611,  722,  667,  944,  667,  667,  611,  278,  278,  278,  469,  556, // 0x50
+     333,  556,  556,  500,  556,  556,  278,  556,  556,  222,  222,  500,  222,  833,  556,  556, // 0x60
+     556,  556,  333,  500,  278,  556,  500,  722,  500,  500,  500,  334,  260,  334,  584,    0, // 0x70
+     556,    0,  222,  556,  333, 1000,  556,  556,  333, 1000,  667,  333, 1000,    0,  611,    0, // 0x80
+       0,  222,  222,  333,  333,  350,  556, 1000,  333, 1000,  500,  333,  944,    0,  500,  667, // 0x90
+     278,  333,  556,  556,  556,  556,  260,  556,  333,  737,  370,  556,  584,  333,  737,  333, // 0xA0
+     400,  584,  333,  333,  333,  556,  537,  278,  333,  333,  365,  556,  834,  834,  834,  611, // 0xB0
+     667,  667,  667,  667,  667,  667, 1000,  722,  667,  667,  667,  667,  278,  278,  278,  278, // 0xC0
+     722,  722,  778,  778,  778,  778,  778,  584,  778,  722,  722,  722,  722,  667,  667,  611, // 0xD0
+     556,  556,  556,  556,  556,  556,  889,  500,  556,  556,  556,  556,  278,  278,  278,  278, // 0xE0
+     556,  556,  556,  556,  556,  556,  556,  584,  611,  556,  556,  556,  556,  500,  556,  500, // 0xF0
+];
+
+/// Helvetica-BoldOblique
+pub(crate) const HELVETICA_BOLD_OBLIQUE: [u16; 224] = [
+     278,  333,  474,  556,  556,  889,  722,  238,  333,  333,  389,  584,  278,  333,  278,  278, // 0x20
+     556,  556,  556,  556,  556,  556,  556,  556,  556,  556,  333,  333,  584,  584,  584,  611, // 0x30
+     975,  722,  722,  722,  722,  667,  611,  778,  722,  278,  556,  722,  611,  833,  722,  778, // 0x40
+     667,  778,  722,  667,  611,  722,  667,  944,  667,  667,  611,  333,  278,  333,  584,  556, // 0x50
+     333,  556,  611,  556,  611,  556,  333,  611,  611,  278,  278,  556,  278,  889,  611,  611, // 0x60
+     611,  611,  389,  556,  333,  611,  556,  778,  556,  556,  500,  389,  280,  389,  584,    0, // 0x70
+     556,    0,  278,  556,  500, 1000,  556,  556,  333, 1000,  667,  333, 1000,    0,  611,    0, // 0x80
+       0,  278,  278,  500,  500,  350,  556, 1000,  333, 1000,  556,  333,  944,    0,  500,  667, // 0x90
+     278,  333,  556,  556,  556,  556,  280,  556,  333,  737,  370,  556,  584,  333,  737,  333, // 0xA0
+     400,  584,  333,  333,  333,  611,  556,  278,  333,  333,  365,  556,  834,  834,  834,  611, // 0xB0
+     722,  722,  722,  722,  722,  722, 1000,  722,  667,  667,  667,  667,  278,  278,  278,  278, // 0xC0
+     722,  722,  778,  778,  778,  778,  778,  584,  778,  722,  722,  722,  722,  667,  667,  611, // 0xD0
+     556,  556,  556,  556,  556,  556,  889,  556,  556,  556,  556,  556,  278,  278,  278,  278, // 0xE0
+     611,  611,  611,  611,  611,  611,  611,  584,  611,  611,  611,  611,  611,  556,  611,  556, // 0xF0
+];
+
+/// Courier
+pub(crate) const COURIER: [u16; 224] = [
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x20
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x30
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x40
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x50
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x60
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,    0, // 0x70
+     600,    0,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,    0,  600,    0, // 0x80
+       0,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,    0,  600,  600, // 0x90
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xA0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xB0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xC0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xD0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xE0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xF0
+];
+
+/// Courier-Bold
+pub(crate) const COURIER_BOLD: [u16; 224] = [
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x20
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x30
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x40
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x50
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x60
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,    0, // 0x70
+     600,    0,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,    0,  600,    0, // 0x80
+       0,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,    0,  600,  600, // 0x90
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xA0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xB0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xC0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xD0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xE0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xF0
+];
+
+/// Courier-Oblique
+pub(crate) const COURIER_OBLIQUE: [u16; 224] = [
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x20
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x30
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x40
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x50
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x60
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,    0, // 0x70
+     600,    0,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,    0,  600,    0, // 0x80
+       0,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,    0,  600,  600, // 0x90
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xA0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xB0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xC0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xD0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xE0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xF0
+];
+
+/// Courier-BoldOblique
+pub(crate) const COURIER_BOLD_OBLIQUE: [u16; 224] = [
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x20
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x30
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x40
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x50
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0x60
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,    0, // 0x70
+     600,    0,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,    0,  600,    0, // 0x80
+       0,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,    0,  600,  600, // 0x90
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xA0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xB0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xC0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xD0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xE0
+     600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600,  600, // 0xF0
+];
+
+/// Symbol, built-in encoding
+pub(crate) const SYMBOL: [u16; 224] = [
+     250,  333,  713,  500,  549,  833,  778,  439,  333,  333,  500,  549,  250,  549,  250,  278, // 0x20
+     500,  500,  500,  500,  500,  500,  500,  500,  500,  500,  278,  278,  549,  549,  549,  444, // 0x30
+     549,  722,  667,  722,  612,  611,  763,  603,  722,  333,  631,  722,  686,  889,  722,  722, // 0x40
+     768,  741,  556,  592,  611,  690,  439,  768,  645,  795,  611,  333,  863,  333,  658,  500, // 0x50
+     500,  631,  549,  549,  494,  439,  521,  411,  603,  329,  603,  549,  549,  576,  521,  549, // 0x60
+     549,  521,  549,  603,  439,  576,  713,  686,  493,  686,  494,  480,  200,  480,  549,    0, // 0x70
+       0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0, // 0x80
+       0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0, // 0x90
+     750,  620,  247,  549,  167,  713,  500,  753,  753,  753,  753, 1042,  987,  603,  987,  603, // 0xA0
+     400,  549,  411,  549,  549,  713,  494,  460,  549,  549,  549,  549, 1000,  603, 1000,  658, // 0xB0
+     823,  686,  795,  987,  768,  768,  823,  768,  768,  713,  713,  713,  713,  713,  713,  713, // 0xC0
+     768,  713,  790,  790,  890,  823,  549,  250,  713,  603,  603, 1042,  987,  603,  987,  603, // 0xD0
+     494,  329,  790,  790,  786,  713,  384,  384,  384,  384,  384,  384,  494,  494,  494,  494, // 0xE0
+       0,  329,  274,  686,  686,  686,  384,  384,  384,  384,  384,  384,  494,  494,  494,    0, // 0xF0
+];
+
+/// unicode character to Symbol code, sorted by character
+pub(crate) const SYMBOL_CODES: [(char, u8); 185] = [
+    (' ', 0x20), ('!', 0x21), ('#', 0x23), ('%', 0x25), ('&', 0x26), ('(', 0x28), (')', 0x29), ('*', 0x2A),
+    ('+', 0x2B), (',', 0x2C), ('-', 0x2D), ('.', 0x2E), ('/', 0x2F), ('0', 0x30), ('1', 0x31), ('2', 0x32),
+    ('3', 0x33), ('4', 0x34), ('5', 0x35), ('6', 0x36), ('7', 0x37), ('8', 0x38), ('9', 0x39), (':', 0x3A),
+    (';', 0x3B), ('<', 0x3C), ('=', 0x3D), ('>', 0x3E), ('?', 0x3F), ('[', 0x5B), (']', 0x5D), ('_', 0x5F),
+    ('{', 0x7B), ('|', 0x7C), ('}', 0x7D), ('©', 0xD3), ('¬', 0xD8), ('®', 0xD2), ('°', 0xB0), ('±', 0xB1),
+    ('×', 0xB4), ('÷', 0xB8), ('ƒ', 0xA6), ('ɸ', 0x66), ('Α', 0x41), ('Β', 0x42), ('Γ', 0x47), ('Δ', 0x44),
+    ('Ε', 0x45), ('Ζ', 0x5A), ('Η', 0x48), ('Θ', 0x51), ('Ι', 0x49), ('Κ', 0x4B), ('Λ', 0x4C), ('Μ', 0x4D),
+    ('Ν', 0x4E), ('Ξ', 0x58), ('Ο', 0x4F), ('Π', 0x50), ('Ρ', 0x52), ('Σ', 0x53), ('Τ', 0x54), ('Υ', 0x55),
+    ('Φ', 0x46), ('Χ', 0x43), ('Ψ', 0x59), ('Ω', 0x57), ('α', 0x61), ('β', 0x62), ('γ', 0x67), ('δ', 0x64),
+    ('ε', 0x65), ('ζ', 0x7A), ('η', 0x68), ('θ', 0x71), ('ι', 0x69), ('κ', 0x6B), ('λ', 0x6C), ('μ', 0x6D),
+    ('ν', 0x6E), ('ξ', 0x78), ('ο', 0x6F), ('π', 0x70), ('ρ', 0x72), ('ς', 0x56), ('σ', 0x73), ('τ', 0x74),
+    ('υ', 0x75), ('φ', 0x6A), ('χ', 0x63), ('ψ', 0x79), ('ω', 0x77), ('ϑ', 0x4A), ('ϒ', 0xA1), ('ϖ', 0x76),
+    ('•', 0xB7), ('…', 0xBC), ('′', 0xA2), ('″', 0xB2), ('⁄', 0xA4), ('€', 0xA0), ('ℑ', 0xC1), ('℘', 0xC3),
+    ('ℜ', 0xC2), ('™', 0xD4), ('ℵ', 0xC0), ('←', 0xAC), ('↑', 0xAD), ('→', 0xAE), ('↓', 0xAF), ('↔', 0xAB),
+    ('↵', 0xBF), ('⇐', 0xDC), ('⇑', 0xDD), ('⇒', 0xDE), ('⇓', 0xDF), ('⇔', 0xDB), ('∀', 0x22), ('∂', 0xB6),
+    ('∃', 0x24), ('∅', 0xC6), ('∇', 0xD1), ('∈', 0xCE), ('∉', 0xCF), ('∋', 0x27), ('∏', 0xD5), ('∑', 0xE5),
+    ('√', 0xD6), ('∝', 0xB5), ('∞', 0xA5), ('∠', 0xD0), ('∧', 0xD9), ('∨', 0xDA), ('∩', 0xC7), ('∪', 0xC8),
+    ('∫', 0xF2), ('∴', 0x5C), ('∼', 0x7E), ('≅', 0x40), ('≈', 0xBB), ('≠', 0xB9), ('≡', 0xBA), ('≤', 0xA3),
+    ('≥', 0xB3), ('⊂', 0xCC), ('⊃', 0xC9), ('⊄', 0xCB), ('⊆', 0xCD), ('⊇', 0xCA), ('⊕', 0xC5), ('⊗', 0xC4),
+    ('⋅', 0xD7), ('⌠', 0xF3), ('⌡', 0xF5), ('〈', 0xE1), ('〉', 0xF1), ('⎛', 0xE6), ('⎜', 0xE7), ('⎝', 0xE8),
+    ('⎞', 0xF6), ('⎟', 0xF7), ('⎠', 0xF8), ('⎡', 0xE9), ('⎢', 0xEA), ('⎣', 0xEB), ('⎤', 0xF9), ('⎥', 0xFA),
+    ('⎦', 0xFB), ('⎧', 0xEC), ('⎨', 0xED), ('⎩', 0xEE), ('⎪', 0xEF), ('⎫', 0xFC), ('⎬', 0xFD), ('⎭', 0xFE),
+    ('⎮', 0xF4), ('⎺', 0x60), ('◊', 0xE0), ('♠', 0xAA), ('♣', 0xA7), ('♥', 0xA9), ('♦', 0xA8), ('⟂', 0x5E),
+    ('⨯', 0xB4),
+];
+
+/// ZapfDingbats, built-in encoding
+pub(crate) const ZAPF_DINGBATS: [u16; 224] = [
+     278,  974,  961,  974,  980,  719,  789,  790,  791,  690,  960,  939,  549,  855,  911,  933, // 0x20
+     911,  945,  974,  755,  846,  762,  761,  571,  677,  763,  760,  759,  754,  494,  552,  537, // 0x30
+     577,  692,  786,  788,  788,  790,  793,  794,  816,  823,  789,  841,  823,  833,  816,  831, // 0x40
+     923,  744,  723,  749,  790,  792,  695,  776,  768,  792,  759,  707,  708,  682,  701,  826, // 0x50
+     815,  789,  789,  707,  687,  696,  689,  786,  787,  713,  791,  785,  791,  873,  761,  762, // 0x60
+     762,  759,  759,  892,  892,  788,  784,  438,  138,  277,  415,  392,  392,  668,  668,    0, // 0x70
+     390,  390,  317,  317,  276,  276,  509,  509,  410,  410,  234,  234,  334,  334,    0,    0, // 0x80
+       0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0, // 0x90
+       0,  732,  544,  544,  910,  667,  760,  760,  776,  595,  694,  626,  788,  788,  788,  788, // 0xA0
+     788,  788,  788,  788,  788,  788,  788,  788,  788,  788,  788,  788,  788,  788,  788,  788, // 0xB0
+     788,  788,  788,  788,  788,  788,  788,  788,  788,  788,  788,  788,  788,  788,  788,  788, // 0xC0
+     788,  788,  788,  788,  894,  838, 1016,  458,  748,  924,  748,  918,  927,  928,  928,  834, // 0xD0
+     873,  828,  924,  924,  917,  930,  931,  463,  883,  836,  836,  867,  867,  696,  696,  874, // 0xE0
+       0,  874,  760,  946,  771,  865,  771,  888,  967,  888,  831,  873,  927,  970,  918,    0, // 0xF0
+];
+
+/// unicode character to ZapfDingbats code, sorted by character
+pub(crate) const ZAPF_DINGBATS_CODES: [(char, u8); 203] = [
+    (' ', 0x20), ('\u{00A0}', 0x20), ('→', 0xD5), ('↔', 0xD6), ('↕', 0xD7), ('①', 0xAC), ('②', 0xAD), ('③', 0xAE),
+    ('④', 0xAF), ('⑤', 0xB0), ('⑥', 0xB1), ('⑦', 0xB2), ('⑧', 0xB3), ('⑨', 0xB4), ('⑩', 0xB5), ('■', 0x6E),
+    ('▲', 0x73), ('▼', 0x74), ('◆', 0x75), ('●', 0x6C), ('◗', 0x77), ('★', 0x48), ('☎', 0x25), ('☛', 0x2A),
+    ('☞', 0x2B), ('♠', 0xAB), ('♣', 0xA8), ('♥', 0xAA), ('♦', 0xA9), ('✁', 0x21), ('✂', 0x22), ('✃', 0x23),
+    ('✄', 0x24), ('✆', 0x26), ('✇', 0x27), ('✈', 0x28), ('✉', 0x29), ('✌', 0x2C), ('✍', 0x2D), ('✎', 0x2E),
+    ('✏', 0x2F), ('✐', 0x30), ('✑', 0x31), ('✒', 0x32), ('✓', 0x33), ('✔', 0x34), ('✕', 0x35), ('✖', 0x36),
+    ('✗', 0x37), ('✘', 0x38), ('✙', 0x39), ('✚', 0x3A), ('✛', 0x3B), ('✜', 0x3C), ('✝', 0x3D), ('✞', 0x3E),
+    ('✟', 0x3F), ('✠', 0x40), ('✡', 0x41), ('✢', 0x42), ('✣', 0x43), ('✤', 0x44), ('✥', 0x45), ('✦', 0x46),
+    ('✧', 0x47), ('✩', 0x49), ('✪', 0x4A), ('✫', 0x4B), ('✬', 0x4C), ('✭', 0x4D), ('✮', 0x4E), ('✯', 0x4F),
+    ('✰', 0x50), ('✱', 0x51), ('✲', 0x52), ('✳', 0x53), ('✴', 0x54), ('✵', 0x55), ('✶', 0x56), ('✷', 0x57),
+    ('✸', 0x58), ('✹', 0x59), ('✺', 0x5A), ('✻', 0x5B), ('✼', 0x5C), ('✽', 0x5D), ('✾', 0x5E), ('✿', 0x5F),
+    ('❀', 0x60), ('❁', 0x61), ('❂', 0x62), ('❃', 0x63), ('❄', 0x64), ('❅', 0x65), ('❆', 0x66), ('❇', 0x67),
+    ('❈', 0x68), ('❉', 0x69), ('❊', 0x6A), ('❋', 0x6B), ('❍', 0x6D), ('❏', 0x6F), ('❐', 0x70), ('❑', 0x71),
+    ('❒', 0x72), ('❖', 0x76), ('❘', 0x78), ('❙', 0x79), ('❚', 0x7A), ('❛', 0x7B), ('❜', 0x7C), ('❝', 0x7D),
+    ('❞', 0x7E), ('❡', 0xA1), ('❢', 0xA2), ('❣', 0xA3), ('❤', 0xA4), ('❥', 0xA5), ('❦', 0xA6), ('❧', 0xA7),
+    ('❨', 0x80), ('❩', 0x81), ('❪', 0x82), ('❫', 0x83), ('❬', 0x84), ('❭', 0x85), ('❮', 0x86), ('❯', 0x87),
+    ('❰', 0x88), ('❱', 0x89), ('❲', 0x8A), ('❳', 0x8B), ('❴', 0x8C), ('❵', 0x8D), ('❶', 0xB6), ('❷', 0xB7),
+    ('❸', 0xB8), ('❹', 0xB9), ('❺', 0xBA), ('❻', 0xBB), ('❼', 0xBC), ('❽', 0xBD), ('❾', 0xBE), ('❿', 0xBF),
+    ('➀', 0xC0), ('➁', 0xC1), ('➂', 0xC2), ('➃', 0xC3), ('➄', 0xC4), ('➅', 0xC5), ('➆', 0xC6), ('➇', 0xC7),
+    ('➈', 0xC8), ('➉', 0xC9), ('➊', 0xCA), ('➋', 0xCB), ('➌', 0xCC), ('➍', 0xCD), ('➎', 0xCE), ('➏', 0xCF),
+    ('➐', 0xD0), ('➑', 0xD1), ('➒', 0xD2), ('➓', 0xD3), ('➔', 0xD4), ('➘', 0xD8), ('➙', 0xD9), ('➚', 0xDA),
+    ('➛', 0xDB), ('➜', 0xDC), ('➝', 0xDD), ('➞', 0xDE), ('➟', 0xDF), ('➠', 0xE0), ('➡', 0xE1), ('➢', 0xE2),
+    ('➣', 0xE3), ('➤', 0xE4), ('➥', 0xE5), ('➦', 0xE6), ('➧', 0xE7), ('➨', 0xE8), ('➩', 0xE9), ('➪', 0xEA),
+    ('➫', 0xEB), ('➬', 0xEC), ('➭', 0xED), ('➮', 0xEE), ('➯', 0xEF), ('➱', 0xF1), ('➲', 0xF2), ('➳', 0xF3),
+    ('➴', 0xF4), ('➵', 0xF5), ('➶', 0xF6), ('➷', 0xF7), ('➸', 0xF8), ('➹', 0xF9), ('➺', 0xFA), ('➻', 0xFB),
+    ('➼', 0xFC), ('➽', 0xFD), ('➾', 0xFE),
+];
@@ -1,14 +1,20 @@
 
 use crate::types::{ 
-    AttributeField,
+    Color,
     FontFamily,
-    Style,
-    TextAlignment
+    FontSlant,
+    QuoteRule,
+    RuleStyle,
+    TextAlignment,
+    TextStyle
 };
 
 /// block level container to push a `Line` ojbect into
+/// 
+/// # Example
 /// ```
-/// Example
+/// # use edra::types::{ TextAlignment, TextBlock };
+/// # let (font_size, alignment, indent) = (12.0, TextAlignment::Left, 0.0);
 /// let mut text_block = TextBlock::new()
 ///     .with_font_size(font_size) // f32
 ///     .and_alignment(alignment)  // TextAlignment
@@ -19,10 +25,28 @@ pub struct TextBlock<'a> {
     pub lines: Vec<Line<'a>>,
     pub font_family: FontFamily,
     pub font_size: f32,
-    // keeps track of which `Line` is currently being pushed to by `Doc::render_text_block()`
+    // keeps track of which `Line` is currently being pushed to by `Doc::layout_text_block()`
     pub index: usize,
     pub indent: f32,
-    pub post_block_offset: f32
+    // space fed above the block, unless it starts a page
+    pub pre_block_offset: f32,
+    // space fed below the block once its last line is written
+    pub post_block_offset: f32,
+    // list markers hung in front of the first line, each inside the indent of the item it numbers
+    pub labels: Vec<Label>,
+    // never split across pages, unless taller than a page
+    pub keep_together: bool,
+    // starts on the same page as the block following it
+    pub keep_with_next: bool,
+    // rules of the blockquotes the block is nested in, drawn down the left of its lines
+    pub quote_rules: Vec<QuoteRule>,
+    // box painted behind every line (code blocks), reaching from `padding` left of the indent to the right margin
+    pub background: Option<Color>,
+    pub padding: f32,
+    // lines are cut off `padding` left of the right margin rather than drawn past it (clipped code blocks)
+    pub clip: bool,
+    // horizontal rule drawn in place of the block's only line
+    pub rule: Option<RuleStyle>,
 }
 
 impl TextBlock<'_> {
@@ -53,17 +77,99 @@ impl TextBlock<'_> {
         self
     }
 
-    /// creates a new, empty, `Line` for `Doc::render_text_block()` to push a `Word` object into
+    /// builder function setting the font family
+    pub fn and_font_family(mut self, font_family: FontFamily) -> Self {
+        self.font_family = font_family;
+        self
+    }
+
+    /// builder function setting the space above the block
+    pub fn and_pre_block_offset(mut self, pre_block_offset: f32) -> Self {
+        self.pre_block_offset = pre_block_offset;
+        self
+    }
+
+    /// builder function setting the space below the block
+    pub fn and_post_block_offset(mut self, post_block_offset: f32) -> Self {
+        self.post_block_offset = post_block_offset;
+        self
+    }
+
+    /// builder function adding a list marker drawn in front of the first line
+    pub fn and_label(mut self, label: Label) -> Self {
+        self.labels.push(label);
+        self
+    }
+
+    /// builder function setting whether the block is kept on one page
+    pub fn and_keep_together(mut self, keep_together: bool) -> Self {
+        self.keep_together = keep_together;
+        self
+    }
+
+    /// builder function setting whether the block starts on the same page as the block following it
+    pub fn and_keep_with_next(mut self, keep_with_next: bool) -> Self {
+        self.keep_with_next = keep_with_next;
+        self
+    }
+
+    /// builder function setting the rules of the blockquotes the block is nested in
+    pub fn and_quote_rules(mut self, quote_rules: Vec<QuoteRule>) -> Self {
+        self.quote_rules = quote_rules;
+        self
+    }
+
+    /// builder function setting the box painted behind the lines of the block
+    pub fn and_background(mut self, background: Option<Color>) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// builder function setting how far the background reaches left of the text
+    pub fn and_padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// builder function setting whether lines are cut off at the right edge of the block
+    pub fn and_clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// builder function setting the horizontal rule the block is drawn as
+    pub fn and_rule(mut self, rule: Option<RuleStyle>) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// creates a new, empty, `Line` for `Doc::layout_text_block()` to push a `Word` object into
     pub fn next(&mut self) {
         self.lines.push(Line {
             body: Vec::new(),
             width: 0.0,
-            offset: 0.0
+            offset: 0.0,
+            font_size: 0.0,
+            char_spacing: 0.0,
+            word_spacing: 0.0,
+            hard_break: false
         });
 
-        // sets the current line index so `Doc::render_text_block()` knows which `Line` to push the next `Word` to
+        // sets the current line index so `Doc::layout_text_block()` knows which `Line` to push the next `Word` to
         self.index += 1;
     }
+
+    /// vertical space each line takes up when written, the same steps `Writer::write()` moves down the page by
+    pub fn line_heights(&self) -> Vec<f32> {
+        self.lines
+            .iter()
+            .map(|line| match (self.rule, line.body.is_empty()) {
+                (Some(rule), _) => rule.thickness,
+                (None, true) => self.font_size * 1.5,
+                (None, false) => line.font_size * 1.5
+            })
+            .collect()
+    }
 }
 
 /// Wrapper for `Word` objects that fit a visual page
@@ -72,15 +178,43 @@ pub struct Line <'a> {
     pub body: Vec<Word<'a>>,
     pub width: f32,
     pub offset: f32,
+    // largest run on the line, sets the line height
+    pub font_size: f32,
+    // extra space after each character (`Tc`) and each space (`Tw`) of a justified line
+    pub char_spacing: f32,
+    pub word_spacing: f32,
+    // ended by a hard break (or a line feed), aligned like the last line of a justified block
+    pub hard_break: bool,
+}
+
+/// indentation, slant and rules a block inherits from the blockquotes it is nested in, built by `Doc::layout_blockquote()`
+#[derive(Debug,Clone,Default)]
+pub struct Nesting {
+    pub indent: f32,
+    pub slant: FontSlant,
+    pub quote_rules: Vec<QuoteRule>,
+}
+
+/// list marker (bullet or number) hung to the left of a `TextBlock` so wrapped lines stay aligned with the text
+/// - the first block of a list item carries its marker whatever its type, a nested list its own marker as well
+#[derive(Debug,Clone)]
+pub struct Label {
+    pub text: String,
+    pub width: f32,
+    /// indent of the item's text, the marker ends half its font size to the left of it
+    pub indent: f32,
+    pub font_size: f32,
+    pub font_family: FontFamily,
 }
 
 /// &str container with word level styles
 #[derive(Debug)]
 pub struct Word <'a>{
-    pub attributes: Option<&'a AttributeField>,
-    pub font_style: Style,
+    pub text_style: TextStyle<'a>,
     pub offset: f32,
     pub text: &'a str,
+    // spaces following the word, drawn only when lines are written as continuous text (`JustifySpacing::Operators`)
+    pub space: &'a str,
     pub width: f32,
 }
 
@@ -94,7 +228,11 @@ impl Default for TextBlock<'_> {
         let line = Line {
             body: Vec::new(),
             width: 0.0,
-            offset: 0.0
+            offset: 0.0,
+            font_size: 0.0,
+            char_spacing: 0.0,
+            word_spacing: 0.0,
+            hard_break: false
         };
 
         TextBlock {
@@ -104,7 +242,16 @@ impl Default for TextBlock<'_> {
             lines: Vec::from([line]),
             index: 0,
             indent: 0.0,
-            post_block_offset: 0.0
+            pre_block_offset: 0.0,
+            post_block_offset: 0.0,
+            labels: Vec::new(),
+            keep_together: false,
+            keep_with_next: false,
+            quote_rules: Vec::new(),
+            background: None,
+            padding: 0.0,
+            clip: false,
+            rule: None
         }
     }
 }
@@ -1,5 +1,5 @@
 /// Generated by Serde from the `textAlign` field
-#[derive(Debug)]
+#[derive(Debug,Clone,Copy,PartialEq)]
 pub enum TextAlignment {
     Left,
     Right,
@@ -0,0 +1,208 @@
+use crate::types::{ Color, FontFamily, FontSize, FontStyle, Script };
+
+/// Weight of the font a text run is drawn with, set by Edra's `bold` mark
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Default)]
+pub enum FontWeight {
+    #[default]
+    Normal,
+    Bold,
+}
+
+/// Slant of the font a text run is drawn with, set by Edra's `italic` mark
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Default)]
+pub enum FontSlant {
+    #[default]
+    Normal,
+    Italic,
+}
+
+/// Independent attributes of a text run, built up from the Edra marks on a `text` node. Any combination of marks
+/// can be represented, underline and strikethrough included.
+///
+/// # Example
+/// ```
+/// # use edra::types::{ Color, FontWeight, Script, TextStyle };
+/// let text_style = TextStyle::new(12.0)      // f32, size before the script is applied
+///     .with_weight(FontWeight::Bold)         // FontWeight
+///     .and_underline(true)                   // bool
+///     .and_strike(true)                      // bool
+///     .and_color(Some(Color::new(204, 0, 0))) // Option<Color>
+///     .and_script(Script::Superscript);      // Script
+///
+/// assert_eq!(text_style.variant(), "bold");
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct TextStyle<'a> {
+    pub weight: FontWeight,
+    pub slant: FontSlant,
+    pub underline: bool,
+    pub strike: bool,
+    /// size of the run, superscript and subscript runs are drawn smaller (see `font_size()`)
+    pub size: f32,
+    /// fill colour, black when `None`
+    pub color: Option<Color>,
+    /// background painted behind the run
+    pub highlight: Option<Color>,
+    pub script: Script,
+    /// destination of a `link` mark
+    pub link: Option<&'a str>,
+    /// set by a `code` mark, the run is drawn in the `CodeStyle` family
+    pub code: bool,
+    /// family of the run, the family of its block when `None`
+    pub family: Option<FontFamily>,
+}
+
+impl<'a> TextStyle<'a> {
+    /// default settings:
+    /// - Weight & slant: normal
+    /// - No underline, strikethrough, colour, highlight or link
+    /// - Script: baseline
+    /// - Family: the block's
+    pub fn new(size: f32) -> Self {
+        TextStyle {
+            weight: FontWeight::Normal,
+            slant: FontSlant::Normal,
+            underline: false,
+            strike: false,
+            size,
+            color: None,
+            highlight: None,
+            script: Script::Baseline,
+            link: None,
+            code: false,
+            family: None,
+        }
+    }
+
+    /// builder function setting the font weight
+    pub fn with_weight(mut self, weight: FontWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// builder function setting the font slant
+    pub fn and_slant(mut self, slant: FontSlant) -> Self {
+        self.slant = slant;
+        self
+    }
+
+    /// builder function setting whether the run is underlined
+    pub fn and_underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// builder function setting whether the run is struck through
+    pub fn and_strike(mut self, strike: bool) -> Self {
+        self.strike = strike;
+        self
+    }
+
+    /// builder function setting the fill colour
+    pub fn and_color(mut self, color: Option<Color>) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// builder function setting the highlight colour
+    pub fn and_highlight(mut self, highlight: Option<Color>) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// builder function setting the vertical position
+    pub fn and_script(mut self, script: Script) -> Self {
+        self.script = script;
+        self
+    }
+
+    /// builder function setting the link destination
+    pub fn and_link(mut self, link: Option<&'a str>) -> Self {
+        self.link = link;
+        self
+    }
+
+    /// builder function setting the family of the run
+    pub fn and_family(mut self, family: Option<FontFamily>) -> Self {
+        self.family = family;
+        self
+    }
+
+    /// size the run is drawn and measured at
+    pub fn font_size(&self) -> f32 {
+        self.script.font_size(self.size)
+    }
+
+    /// baseline shift of the run, positive values raise it
+    pub fn rise(&self) -> f32 {
+        self.script.rise(self.size)
+    }
+
+    /// label suffix of the font variant the run is drawn with (`"normal"`, `"bold"`, `"italic"`, `"bold-italic"`)
+    pub fn variant(&self) -> &'static str {
+        match (self.weight, self.slant) {
+            (FontWeight::Normal, FontSlant::Normal) => "normal",
+            (FontWeight::Bold, FontSlant::Normal) => "bold",
+            (FontWeight::Normal, FontSlant::Italic) => "italic",
+            (FontWeight::Bold, FontSlant::Italic) => "bold-italic",
+        }
+    }
+
+    /// adds the attribute a mark describes, unknown marks are ignored
+    /// - `textStyle` sizes are relative to the size the style was created with
+    /// - `highlight` is yellow unless the mark has a `color` attribute that can be parsed
+    /// - `link` marks without an `href` aren't links
+    ///
+    /// ```
+    /// # use edra::types::{ Color, FontStyle, TextStyle };
+    /// let mark: FontStyle = serde_json::from_str(r#"{ "type": "highlight", "attrs": { "color": "var(--brand)" } }"#)
+    ///     .expect("invalid json");
+    /// let mut text_style = TextStyle::new(12.0);
+    ///
+    /// text_style.apply(&mark);
+    ///
+    /// assert_eq!(text_style.highlight, Some(Color::HIGHLIGHT));
+    /// ```
+    pub fn apply(&mut self, mark: &'a FontStyle) {
+        let attributes = mark.attributes();
+
+        match mark.name.as_deref() {
+            Some("bold") => self.weight = FontWeight::Bold,
+            Some("italic") => self.slant = FontSlant::Italic,
+            Some("underline") => self.underline = true,
+            Some("strike") => self.strike = true,
+            Some("superscript") => self.script = Script::Superscript,
+            Some("subscript") => self.script = Script::Subscript,
+            Some("code") => self.code = true,
+            Some("textStyle") => {
+                if let Some(font_size) = attributes.and_then(|attribute_field| attribute_field.font_size.as_deref()).and_then(FontSize::parse) {
+                    self.size = font_size.resolve(self.size);
+                }
+
+                if let Some(color) = attributes.and_then(|attribute_field| attribute_field.color.as_deref()).and_then(Color::parse) {
+                    self.color = Some(color);
+                }
+            },
+            Some("highlight") => {
+                self.highlight = attributes
+                    .and_then(|attribute_field| attribute_field.color.as_deref())
+                    .and_then(Color::parse)
+                    .or(Some(Color::HIGHLIGHT));
+            },
+            Some("link") => {
+                self.link = attributes
+                    .and_then(|attribute_field| attribute_field.href.as_deref())
+                    .filter(|href| !href.trim().is_empty());
+            },
+            _ => {}
+        }
+    }
+}
+
+impl Default for TextStyle<'_> {
+    /// default settings:
+    /// - Size: 12.0
+    fn default() -> Self {
+        TextStyle::new(12.0)
+    }
+}
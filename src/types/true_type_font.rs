@@ -0,0 +1,571 @@
+use std::collections::{ BTreeSet, HashMap };
+use crate::types::RenderError;
+
+// composite glyph flags, see the `glyf` table in the OpenType spec
+const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+const WE_HAVE_A_SCALE: u16 = 0x0008;
+const MORE_COMPONENTS: u16 = 0x0020;
+const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+
+// tables copied into a subsetted TrueType font, everything else is dropped
+const SUBSET_TABLES: [&[u8; 4]; 9] = [b"cvt ", b"fpgm", b"glyf", b"head", b"hhea", b"hmtx", b"loca", b"maxp", b"prep"];
+
+/// # TrueType/OpenType font file
+/// Parses the tables needed to measure, encode and embed a font in the PDF:
+/// - glyph advance widths from `hmtx`
+/// - character to glyph mapping from `cmap` (formats 4 and 12)
+/// - font descriptor metrics from `head`, `hhea`, `OS/2`, `post` and `name`
+///
+/// TrueType outlines (`glyf`) are subsetted to the glyphs used in the document, CFF based
+/// OpenType fonts are embedded whole.
+///
+/// ```no_run
+/// # use edra::types::TrueTypeFont;
+/// let data = std::fs::read("./Brand-Regular.ttf").unwrap();
+/// let font = TrueTypeFont::from_bytes(data).unwrap();
+/// ```
+#[derive(Debug,Clone)]
+pub struct TrueTypeFont {
+    data: Vec<u8>,
+    tables: HashMap<[u8; 4], (usize, usize)>,
+    advances: Vec<u16>,
+    glyph_ids: HashMap<char, u16>,
+    num_glyphs: u16,
+    long_loca: bool,
+    /// PostScript name from the `name` table, used as the PDF `BaseFont`
+    pub postscript_name: String,
+    pub units_per_em: u16,
+    pub ascent: i16,
+    pub descent: i16,
+    pub cap_height: i16,
+    /// xMin, yMin, xMax, yMax in font units
+    pub bbox: [i16; 4],
+    pub italic_angle: f32,
+    pub weight: u16,
+    pub fixed_pitch: bool,
+    /// true for OpenType fonts with CFF outlines (`OTTO`)
+    pub cff: bool,
+}
+
+impl TrueTypeFont {
+    /// parses a `.ttf` or `.otf` file, font collections (`.ttc`) are not supported
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, RenderError> {
+        let cff = match read_u32(&data, 0)? {
+            0x00010000 | 0x74727565 => false, // 1.0 | 'true'
+            0x4F54544F => true,               // 'OTTO'
+            0x74746366 => return Err(invalid("font collections are not supported")),
+            _ => return Err(invalid("not a TrueType or OpenType font"))
+        };
+
+        let num_tables = read_u16(&data, 4)? as usize;
+        let mut tables = HashMap::with_capacity(num_tables);
+
+        for index in 0..num_tables {
+            let record = 12 + index * 16;
+            let tag = data.get(record..record + 4).ok_or_else(|| invalid("truncated table directory"))?;
+            let offset = read_u32(&data, record + 8)? as usize;
+            let length = read_u32(&data, record + 12)? as usize;
+
+            if offset.checked_add(length).is_none_or(|end| end > data.len()) {
+                return Err(invalid("table extends past the end of the file"));
+            }
+
+            tables.insert([tag[0], tag[1], tag[2], tag[3]], (offset, length));
+        }
+
+        let mut font = TrueTypeFont {
+            data,
+            tables,
+            advances: Vec::new(),
+            glyph_ids: HashMap::new(),
+            num_glyphs: 0,
+            long_loca: false,
+            postscript_name: String::new(),
+            units_per_em: 1000,
+            ascent: 0,
+            descent: 0,
+            cap_height: 0,
+            bbox: [0; 4],
+            italic_angle: 0.0,
+            weight: 400,
+            fixed_pitch: false,
+            cff,
+        };
+
+        font.parse_head()?;
+        font.parse_hhea_and_hmtx()?;
+        font.parse_cmap()?;
+        font.parse_os2()?;
+        font.parse_post()?;
+        font.parse_name()?;
+
+        Ok(font)
+    }
+
+    /// returns the glyph id mapped to a character by the `cmap` table
+    pub fn glyph_id(&self, ch: char) -> Option<u16> {
+        self.glyph_ids.get(&ch).copied()
+    }
+
+    /// advance width of a glyph in PDF text space units (1/1000 em)
+    pub fn glyph_advance(&self, glyph_id: u16) -> f32 {
+        let advance = self.advances
+            .get(glyph_id as usize)
+            .or(self.advances.last())
+            .copied()
+            .unwrap_or(0);
+
+        self.to_pdf_units(advance as f32)
+    }
+
+    /// width of a character at the given font size, unmapped characters use the `.notdef` glyph
+    pub fn char_width(&self, ch: &char, font_size: f32) -> f32 {
+        let glyph_id = self.glyph_id(*ch).unwrap_or(0);
+        self.glyph_advance(glyph_id) * font_size / 1000.0
+    }
+
+    /// converts font units to PDF text space units (1/1000 em)
+    pub fn to_pdf_units(&self, value: f32) -> f32 {
+        value * 1000.0 / self.units_per_em as f32
+    }
+
+    /// returns the font program to embed, TrueType outlines are reduced to `glyphs` and any composite glyph components
+    /// - glyph ids are kept stable so an `Identity` CIDToGIDMap can be used
+    pub fn subset(&self, glyphs: &BTreeSet<u16>) -> Result<Vec<u8>, RenderError> {
+        if self.cff {
+            return Ok(self.data.clone());
+        }
+
+        let glyf = self.table(b"glyf")?;
+        let loca = self.glyph_locations()?;
+
+        // .notdef must always be present
+        let mut keep: BTreeSet<u16> = BTreeSet::new();
+        let mut pending: Vec<u16> = glyphs.iter().copied().collect();
+        pending.push(0);
+
+        while let Some(glyph_id) = pending.pop() {
+            if glyph_id >= self.num_glyphs || !keep.insert(glyph_id) {
+                continue;
+            }
+
+            let (start, end) = loca[glyph_id as usize];
+            let outline = glyf.get(start..end).ok_or_else(|| invalid("glyph outside of glyf table"))?;
+
+            for component in composite_components(outline)? {
+                if !keep.contains(&component) {
+                    pending.push(component);
+                }
+            }
+        }
+
+        let mut new_glyf: Vec<u8> = Vec::new();
+        let mut new_loca: Vec<u8> = Vec::with_capacity((self.num_glyphs as usize + 1) * 4);
+
+        for glyph_id in 0..self.num_glyphs {
+            new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+
+            if keep.contains(&glyph_id) {
+                let (start, end) = loca[glyph_id as usize];
+                new_glyf.extend_from_slice(&glyf[start..end]);
+                new_glyf.resize(new_glyf.len().div_ceil(4) * 4, 0);
+            }
+        }
+
+        new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+
+        // long loca offsets and a zeroed checksum adjustment
+        let mut new_head = self.table(b"head")?.to_vec();
+        new_head[8..12].copy_from_slice(&[0; 4]);
+        new_head[50..52].copy_from_slice(&1i16.to_be_bytes());
+
+        let mut tables: Vec<(&[u8; 4], Vec<u8>)> = Vec::with_capacity(SUBSET_TABLES.len());
+
+        for tag in SUBSET_TABLES {
+            let table = match tag {
+                b"glyf" => new_glyf.clone(),
+                b"loca" => new_loca.clone(),
+                b"head" => new_head.clone(),
+                _ => match self.tables.contains_key(tag) {
+                    true => self.table(tag)?.to_vec(),
+                    false => continue
+                }
+            };
+
+            tables.push((tag, table));
+        }
+
+        Ok(write_sfnt(tables))
+    }
+
+    fn table(&self, tag: &[u8; 4]) -> Result<&[u8], RenderError> {
+        let (offset, length) = self.tables
+            .get(tag)
+            .ok_or_else(|| invalid(&format!("missing `{}` table", String::from_utf8_lossy(tag))))?;
+
+        Ok(&self.data[*offset..*offset + *length])
+    }
+
+    fn parse_head(&mut self) -> Result<(), RenderError> {
+        let head = self.table(b"head")?;
+
+        let units_per_em = read_u16(head, 18)?;
+        let bbox = [read_i16(head, 36)?, read_i16(head, 38)?, read_i16(head, 40)?, read_i16(head, 42)?];
+        let long_loca = read_i16(head, 50)? == 1;
+
+        if units_per_em == 0 {
+            return Err(invalid("unitsPerEm is zero"));
+        }
+
+        self.units_per_em = units_per_em;
+        self.bbox = bbox;
+        self.long_loca = long_loca;
+
+        Ok(())
+    }
+
+    fn parse_hhea_and_hmtx(&mut self) -> Result<(), RenderError> {
+        let hhea = self.table(b"hhea")?;
+        let ascent = read_i16(hhea, 4)?;
+        let descent = read_i16(hhea, 6)?;
+        let number_of_h_metrics = read_u16(hhea, 34)? as usize;
+        let num_glyphs = read_u16(self.table(b"maxp")?, 4)?;
+
+        let hmtx = self.table(b"hmtx")?;
+        let mut advances = Vec::with_capacity(num_glyphs as usize);
+
+        for index in 0..number_of_h_metrics.min(num_glyphs as usize) {
+            advances.push(read_u16(hmtx, index * 4)?);
+        }
+
+        // glyphs past `numberOfHMetrics` repeat the last advance
+        let last = advances.last().copied().unwrap_or(0);
+        advances.resize(num_glyphs as usize, last);
+
+        self.ascent = ascent;
+        self.descent = descent;
+        self.num_glyphs = num_glyphs;
+        self.advances = advances;
+
+        Ok(())
+    }
+
+    fn parse_cmap(&mut self) -> Result<(), RenderError> {
+        let cmap = self.table(b"cmap")?;
+        let num_tables = read_u16(cmap, 2)? as usize;
+        let mut best: Option<(u8, usize)> = None;
+
+        // prefer full unicode (format 12) over the basic multilingual plane (format 4)
+        for index in 0..num_tables {
+            let record = 4 + index * 8;
+            let platform = read_u16(cmap, record)?;
+            let encoding = read_u16(cmap, record + 2)?;
+            let offset = read_u32(cmap, record + 4)? as usize;
+            let format = read_u16(cmap, offset)?;
+
+            let rank = match (platform, encoding, format) {
+                (3, 10, 12) | (0, 4, 12) | (0, 6, 12) => 3,
+                (3, 1, 4) | (0, 3, 4) => 2,
+                (0, _, 4) => 1,
+                _ => 0
+            };
+
+            if rank > 0 && best.is_none_or(|(best_rank, _)| rank > best_rank) {
+                best = Some((rank, offset));
+            }
+        }
+
+        let (_, offset) = best.ok_or_else(|| invalid("no unicode cmap subtable"))?;
+        let subtable = &cmap[offset..];
+
+        let glyph_ids = match read_u16(subtable, 0)? {
+            4 => parse_cmap_format_4(subtable)?,
+            _ => parse_cmap_format_12(subtable)?
+        };
+
+        self.glyph_ids = glyph_ids;
+
+        Ok(())
+    }
+
+    fn parse_os2(&mut self) -> Result<(), RenderError> {
+        // OS/2 is required by the spec but missing from some older Mac fonts
+        if !self.tables.contains_key(b"OS/2") {
+            self.cap_height = self.ascent;
+            return Ok(());
+        }
+
+        let os2 = self.table(b"OS/2")?;
+        let weight = read_u16(os2, 4)?;
+        let version = read_u16(os2, 0)?;
+        let cap_height = match version >= 2 {
+            true => read_i16(os2, 88).unwrap_or(self.ascent),
+            false => self.ascent
+        };
+
+        self.weight = weight;
+        self.cap_height = cap_height;
+
+        Ok(())
+    }
+
+    fn parse_post(&mut self) -> Result<(), RenderError> {
+        let post = self.table(b"post")?;
+        let italic_angle = read_u32(post, 4)? as i32 as f32 / 65536.0;
+        let fixed_pitch = read_u32(post, 12)? != 0;
+
+        self.italic_angle = italic_angle;
+        self.fixed_pitch = fixed_pitch;
+
+        Ok(())
+    }
+
+    fn parse_name(&mut self) -> Result<(), RenderError> {
+        let name = self.table(b"name")?;
+        let count = read_u16(name, 2)? as usize;
+        let storage = read_u16(name, 4)? as usize;
+        let mut postscript_name: Option<String> = None;
+
+        for index in 0..count {
+            let record = 6 + index * 12;
+            let platform = read_u16(name, record)?;
+            let name_id = read_u16(name, record + 6)?;
+            let length = read_u16(name, record + 8)? as usize;
+            let offset = read_u16(name, record + 10)? as usize;
+
+            if name_id != 6 {
+                continue;
+            }
+
+            let bytes = name
+                .get(storage + offset..storage + offset + length)
+                .ok_or_else(|| invalid("name record outside of name table"))?;
+
+            postscript_name = match platform {
+                // windows and unicode platforms store UTF-16BE
+                0 | 3 => {
+                    let units: Vec<u16> = bytes.chunks_exact(2).map(|pair| u16::from_be_bytes([pair[0], pair[1]])).collect();
+                    Some(String::from_utf16_lossy(&units))
+                },
+                _ => Some(bytes.iter().map(|byte| *byte as char).collect())
+            };
+
+            break;
+        }
+
+        // PDF names can't contain whitespace or delimiters
+        let postscript_name: String = postscript_name
+            .unwrap_or_else(|| "EmbeddedFont".to_string())
+            .chars()
+            .filter(|ch| ch.is_ascii_alphanumeric() || *ch == '-' || *ch == '_')
+            .collect();
+
+        self.postscript_name = match postscript_name.is_empty() {
+            true => "EmbeddedFont".to_string(),
+            false => postscript_name
+        };
+
+        Ok(())
+    }
+
+    /// (start, end) of each glyph inside the `glyf` table
+    fn glyph_locations(&self) -> Result<Vec<(usize, usize)>, RenderError> {
+        let loca = self.table(b"loca")?;
+        let glyf_length = self.table(b"glyf")?.len();
+        let mut offsets: Vec<usize> = Vec::with_capacity(self.num_glyphs as usize + 1);
+
+        for index in 0..=self.num_glyphs as usize {
+            let offset = match self.long_loca {
+                true => read_u32(loca, index * 4)? as usize,
+                false => read_u16(loca, index * 2)? as usize * 2
+            };
+
+            offsets.push(offset.min(glyf_length));
+        }
+
+        Ok(offsets.windows(2).map(|pair| (pair[0], pair[1].max(pair[0]))).collect())
+    }
+}
+
+fn invalid(reason: &str) -> RenderError {
+    RenderError::InvalidFont(reason.to_string())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, RenderError> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+        .ok_or_else(|| invalid("unexpected end of font data"))
+}
+
+fn read_i16(data: &[u8], offset: usize) -> Result<i16, RenderError> {
+    read_u16(data, offset).map(|value| value as i16)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, RenderError> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .ok_or_else(|| invalid("unexpected end of font data"))
+}
+
+/// segment mapping to delta values, covers the basic multilingual plane
+fn parse_cmap_format_4(subtable: &[u8]) -> Result<HashMap<char, u16>, RenderError> {
+    let seg_count = read_u16(subtable, 6)? as usize / 2;
+    let end_codes = 14;
+    let start_codes = end_codes + seg_count * 2 + 2;
+    let id_deltas = start_codes + seg_count * 2;
+    let id_range_offsets = id_deltas + seg_count * 2;
+    let mut glyph_ids = HashMap::new();
+
+    for segment in 0..seg_count {
+        let end = read_u16(subtable, end_codes + segment * 2)?;
+        let start = read_u16(subtable, start_codes + segment * 2)?;
+        let delta = read_u16(subtable, id_deltas + segment * 2)?;
+        let range_offset_position = id_range_offsets + segment * 2;
+        let range_offset = read_u16(subtable, range_offset_position)? as usize;
+
+        if start > end {
+            continue;
+        }
+
+        for code in start..=end {
+            if code == 0xFFFF {
+                break;
+            }
+
+            let glyph_id = match range_offset {
+                0 => code.wrapping_add(delta),
+                _ => {
+                    let position = range_offset_position + range_offset + (code - start) as usize * 2;
+                    match read_u16(subtable, position)? {
+                        0 => 0,
+                        glyph_id => glyph_id.wrapping_add(delta)
+                    }
+                }
+            };
+
+            if let (Some(ch), true) = (char::from_u32(code as u32), glyph_id != 0) {
+                glyph_ids.insert(ch, glyph_id);
+            }
+        }
+    }
+
+    Ok(glyph_ids)
+}
+
+/// segmented coverage, covers the full unicode range
+fn parse_cmap_format_12(subtable: &[u8]) -> Result<HashMap<char, u16>, RenderError> {
+    let groups = read_u32(subtable, 12)? as usize;
+    let mut glyph_ids = HashMap::new();
+
+    for group in 0..groups {
+        let record = 16 + group * 12;
+        let start = read_u32(subtable, record)?;
+        let end = read_u32(subtable, record + 4)?;
+        let start_glyph = read_u32(subtable, record + 8)?;
+
+        if start > end || end > 0x10FFFF {
+            continue;
+        }
+
+        for code in start..=end {
+            let glyph_id = start_glyph + (code - start);
+
+            if let (Some(ch), Ok(glyph_id)) = (char::from_u32(code), u16::try_from(glyph_id)) {
+                if glyph_id != 0 {
+                    glyph_ids.insert(ch, glyph_id);
+                }
+            }
+        }
+    }
+
+    Ok(glyph_ids)
+}
+
+/// glyph ids referenced by a composite glyph, empty for simple glyphs
+fn composite_components(outline: &[u8]) -> Result<Vec<u16>, RenderError> {
+    let mut components = Vec::new();
+
+    if outline.is_empty() || read_i16(outline, 0)? >= 0 {
+        return Ok(components);
+    }
+
+    let mut offset = 10;
+
+    loop {
+        let flags = read_u16(outline, offset)?;
+        components.push(read_u16(outline, offset + 2)?);
+        offset += 4;
+
+        offset += match flags & ARG_1_AND_2_ARE_WORDS != 0 {
+            true => 4,
+            false => 2
+        };
+
+        if flags & WE_HAVE_A_SCALE != 0 {
+            offset += 2;
+        } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+            offset += 4;
+        } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+            offset += 8;
+        }
+
+        if flags & MORE_COMPONENTS == 0 {
+            break;
+        }
+    }
+
+    Ok(components)
+}
+
+/// sums a table as big endian u32 values as required by the table directory
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+/// assembles a TrueType font file from tables, which must be sorted by tag
+fn write_sfnt(tables: Vec<(&[u8; 4], Vec<u8>)>) -> Vec<u8> {
+    let num_tables = tables.len() as u16;
+    let entry_selector = (num_tables as f32).log2().floor() as u16;
+    let search_range = 2u16.pow(entry_selector as u32) * 16;
+    let range_shift = num_tables * 16 - search_range;
+
+    let mut font: Vec<u8> = Vec::new();
+    font.extend(0x00010000u32.to_be_bytes());
+    font.extend(num_tables.to_be_bytes());
+    font.extend(search_range.to_be_bytes());
+    font.extend(entry_selector.to_be_bytes());
+    font.extend(range_shift.to_be_bytes());
+
+    let mut offset = 12 + tables.len() * 16;
+    let mut head_offset = None;
+
+    for (tag, table) in tables.iter() {
+        font.extend_from_slice(*tag);
+        font.extend(checksum(table).to_be_bytes());
+        font.extend((offset as u32).to_be_bytes());
+        font.extend((table.len() as u32).to_be_bytes());
+
+        if *tag == b"head" {
+            head_offset = Some(offset);
+        }
+
+        offset += table.len().div_ceil(4) * 4;
+    }
+
+    for (_, table) in tables.iter() {
+        font.extend_from_slice(table);
+        font.resize(font.len().div_ceil(4) * 4, 0);
+    }
+
+    if let Some(head_offset) = head_offset {
+        let adjustment = 0xB1B0AFBAu32.wrapping_sub(checksum(&font));
+        font[head_offset + 8..head_offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+    }
+
+    font
+}
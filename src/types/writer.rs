@@ -1,16 +1,44 @@
-use std::collections::HashMap;
+use std::collections::{ BTreeMap, HashMap };
+use std::ops::Range;
 
-use pdf_writer::{ Content, Str, Ref };
+use pdf_writer::{ Content, Rect, Str, Ref };
+use pdf_writer::types::LineCapStyle;
 
 use crate::{
     traits::FontType, 
     types::{ 
-        Font,
+        BlockType,
+        CodeStyle,
+        Color,
+        EmbeddedFont,
+        FontFamily,
         FontReference,
+        Fragment,
+        HeaderFooter,
+        HeadingStyles,
+        Hyphenation,
+        Justification,
+        JustifySpacing,
+        Line,
+        LineBreaking,
+        LineStyle,
+        LinkAnnotation,
+        LinkStyle,
+        Margins,
         Page,
+        PageSetup,
+        QuoteRule,
+        QuoteStyle,
         PageContent, 
-        Style,
-        TextBlock
+        Pagination,
+        RenderError,
+        RuleStyle,
+        FontSlant,
+        FontWeight,
+        TextBlock,
+        TextStyle,
+        TrueTypeFont,
+        Word
 }};
 
 /// the rendering engine
@@ -18,6 +46,7 @@ use crate::{
 /// - page references
 /// - ref allocator
 /// - font family mapping
+/// - embedded font glyph usage
 /// - default page settings
 pub struct Writer<'a> {
     pub x: f32,
@@ -25,23 +54,47 @@ pub struct Writer<'a> {
     pub alloc: Ref,
     pub current_page: Option<Ref>,
     pub font_refs: Vec<FontReference<'a>>,
-    pub font_family: HashMap<&'a str,Font>,
+    pub font_family: HashMap<&'a str, &'a dyn FontType>,
+    pub embedded_font: Option<&'a EmbeddedFont>,
+    // family used for every `TextBlock` without a family of its own in `block_families`
+    pub default_family: FontFamily,
+    pub block_families: HashMap<BlockType, FontFamily>,
+    pub link_style: LinkStyle,
+    pub line_breaking: LineBreaking,
+    pub hyphenation: Option<Hyphenation>,
+    pub justification: Justification,
+    pub pagination: Pagination,
+    pub header: Option<HeaderFooter>,
+    pub footer: Option<HeaderFooter>,
+    pub heading_styles: HeadingStyles,
+    pub quote_style: QuoteStyle,
+    pub code_style: CodeStyle,
+    pub rule_style: RuleStyle,
+    // glyphs (and the character they were drawn for) written with each embedded font, keyed by font reference
+    pub glyph_usage: HashMap<Ref, BTreeMap<u16, char>>,
     pub pages: Vec<Page>,
     pub page_height: f32,
     pub page_width: f32,
-    pub page_margin: f32,
+    pub page_margins: Margins,
 }
 
-/// Sets the write head to x: 0, y: 0, embeds the Times-Roman font, instantiates the Ref Allocator
+/// Sets the write head to x: 0, y: 0 on an A4 portrait page with 1/2 inch margins, instantiates the Ref Allocator
 impl Default for Writer<'_> {
     fn default() -> Self {
+        Writer::new(&PageSetup::default())
+    }   
+}
+
+impl Writer <'_> {
+    /// Sets the write head to x: 0, y: 0, sizes every page from `page_setup`, instantiates the Ref Allocator
+    pub fn new(page_setup: &PageSetup) -> Self {
         let mut alloc = Ref::new(1);
         let mut contents:Vec<PageContent> = Vec::new();
         let mut pages: Vec<Page> = Vec::with_capacity(1);
 
-        let page_height: f32 = 842.4;
-        let page_width: f32 = 595.6;
-        let page_margin: f32 = 48.0;
+        let page_height: f32 = page_setup.height();
+        let page_width: f32 = page_setup.width();
+        let page_margins: Margins = page_setup.margins;
         let first_page_ref = alloc.bump();
         let first_content_ref = alloc.bump();
         let content_obj = Content::new();
@@ -56,6 +109,7 @@ impl Default for Writer<'_> {
         let page = Page {
             page_id: first_page_ref,
             contents,
+            links: Vec::new(),
         };
 
         pages.push(page);
@@ -67,15 +121,28 @@ impl Default for Writer<'_> {
             current_page: Some(first_page_ref),
             font_refs: Vec::with_capacity(4),
             font_family: HashMap::with_capacity(1),
+            embedded_font: None,
+            default_family: FontFamily::TimesRoman,
+            block_families: HashMap::new(),
+            link_style: LinkStyle::default(),
+            line_breaking: LineBreaking::default(),
+            hyphenation: None,
+            justification: Justification::default(),
+            pagination: Pagination::default(),
+            header: None,
+            footer: None,
+            heading_styles: HeadingStyles::default(),
+            quote_style: QuoteStyle::default(),
+            code_style: CodeStyle::default(),
+            rule_style: RuleStyle::default(),
+            glyph_usage: HashMap::new(),
             pages,
             page_height,
             page_width,
-            page_margin,
+            page_margins,
         }
-    }   
-}
+    }
 
-impl Writer <'_> {
     /// get a new reference for indirect object
     pub fn bump(&mut self) -> Ref {
         self.alloc.bump()
@@ -86,27 +153,218 @@ impl Writer <'_> {
         self.y -= num;
     }
 
+    /// width available for text between the left and right margins
+    pub fn writeable_width(&self) -> f32 {
+        self.page_width - self.page_margins.left - self.page_margins.right
+    }
+
+    /// y position of the first line on a page, below the header
+    pub fn top(&self) -> f32 {
+        self.page_height - self.page_margins.top - self.header.as_ref().map_or(0.0, HeaderFooter::height)
+    }
+
+    /// lowest y position a line may be written to, above the footer
+    pub fn bottom(&self) -> f32 {
+        self.page_margins.bottom + self.footer.as_ref().map_or(0.0, HeaderFooter::height)
+    }
+
+    /// family used to render blocks of `block_type`
+    pub fn family_for(&self, block_type: BlockType) -> FontFamily {
+        self.block_families
+            .get(&block_type)
+            .copied()
+            .unwrap_or(self.default_family)
+    }
+
+    /// style whose widths match the font `text_style` text is drawn with in `family`
+    /// - variants missing from a registered family are drawn, and so measured, with its normal variant
+    pub fn measured_style<'s>(&self, family: FontFamily, text_style: &TextStyle<'s>) -> TextStyle<'s> {
+        let label = format!("{}-{}", family.key(), text_style.variant());
+
+        match self.font_refs.iter().any(|ref_obj| ref_obj.label == label) {
+            true => *text_style,
+            false => text_style.with_weight(FontWeight::Normal).and_slant(FontSlant::Normal)
+        }
+    }
+
     /// moves the writer to a new position
     pub fn go_to(&mut self, num_x: f32, num_y: f32) {
         self.x = num_x;
         self.y = num_y;
     }
 
-    /// does the heavy lifting of rendering the `TextBlock` to `self.current_page`
-    pub fn write(&mut self, text_block: TextBlock) {
+    /// adds a new, empty, `Page` and moves the writer to its top
+    pub fn new_page(&mut self) {
+        let page_id = self.bump();
+        let content_id = self.bump();
+
+        self.pages.push(Page {
+            page_id,
+            contents: Vec::from([PageContent { content_id, content: Content::new() }]),
+            links: Vec::new()
+        });
+
+        self.current_page = Some(page_id);
+        self.y = self.top();
+    }
+
+    /// writes the lines of each `Fragment`, starting a new `Page` whenever a fragment belongs to the next one
+    /// - each quote rule runs down the consecutive fragments nested in its quote, and is continued on the next page
+    pub fn write_fragments(&mut self, blocks: &[TextBlock], fragments: &[Fragment]) -> Result<(), RenderError> {
+        // rules running down the current page, outermost quote first, with the top and bottom of each
+        let mut quote_rules: Vec<(QuoteRule, f32, f32)> = Vec::new();
+
+        for fragment in fragments {
+            if self.pages.len() <= fragment.page {
+                self.draw_quote_rules(quote_rules.drain(..))?;
+            }
+
+            while self.pages.len() <= fragment.page {
+                self.new_page();
+            }
+
+            let text_block = &blocks[fragment.block];
+            // a line reaches one block font size above the writer, the space above the block included
+            let top = self.y + text_block.font_size;
+
+            self.write(text_block, fragment.lines.clone())?;
+
+            // the space below the block doesn't extend the rule past the last quoted line
+            let bottom = match fragment.lines.end == text_block.lines.len() {
+                true => self.y + text_block.post_block_offset + text_block.font_size,
+                false => self.y + text_block.font_size
+            };
+
+            // rules of quotes the block isn't nested in end with the previous block
+            let shared = quote_rules
+                .iter()
+                .zip(text_block.quote_rules.iter())
+                .take_while(|((open, _, _), quote_rule)| open == *quote_rule)
+                .count();
+
+            self.draw_quote_rules(quote_rules.drain(shared..))?;
+
+            for (_, _, open_bottom) in quote_rules.iter_mut() {
+                *open_bottom = bottom;
+            }
+
+            quote_rules.extend(text_block.quote_rules[shared..].iter().map(|quote_rule| (*quote_rule, top, bottom)));
+        }
+
+        self.draw_quote_rules(quote_rules.drain(..))
+    }
+
+    /// writes header or footer text at each x position on the baseline `y` of a zero based page, in the normal variant of the default family
+    pub fn write_running_text(&mut self, page: usize, y: f32, texts: &[(f32, String)], font_size: f32) -> Result<(), RenderError> {
+        let label = format!("{}-normal", self.default_family.key());
+        let ref_obj = self.font_refs
+            .iter()
+            .find(|ref_obj| ref_obj.label == label)
+            .ok_or_else(|| RenderError::MissingFont(label.clone()))?;
+        let content = self.pages
+            .get_mut(page)
+            .and_then(|page| page.contents.last_mut())
+            .ok_or(RenderError::MissingPage)?;
+
+        for (x, text) in texts {
+            let text_bytes = Writer::encode(ref_obj, text, &mut self.glyph_usage);
+
+            content.content.begin_text();
+            content.content.set_font(ref_obj.name, font_size);
+            content.content.next_line(*x, y);
+            content.content.show(Str(&text_bytes));
+            content.content.end_text();
+        }
+
+        Ok(())
+    }
+
+    /// does the heavy lifting of rendering the `lines` of a `TextBlock` to `self.current_page`
+    /// - the space below the block is fed once its last line is written
+    pub fn write(&mut self, text_block: &TextBlock, lines: Range<usize>) -> Result<(), RenderError> {
         // a page must exist by now
-        debug_assert!(self.pages.is_empty() == false);
-        // fonts must exist by now
-        debug_assert!(self.font_refs.is_empty() == false);
+        if self.pages.is_empty() {
+            return Err(RenderError::MissingPage);
+        }
 
         let block_indent = text_block.indent;
         let mut font_map: HashMap<&str, &FontReference> = HashMap::with_capacity(self.font_refs.len());
         
         for font in self.font_refs.iter() {
-            font_map.insert(font.label,font);
+            font_map.insert(font.label.as_str(), font);
         }
 
-        for line in text_block.lines.iter() {
+        let last = lines.end == text_block.lines.len();
+
+        for (index, line) in text_block.lines.iter().enumerate().take(lines.end).skip(lines.start) {
+            // space above a block is dropped at the top of a page
+            if index == 0 && self.y < self.top() {
+                self.y -= text_block.pre_block_offset;
+            }
+
+            // list markers hang inside the indent of their item, level with the first line
+            if index == 0 && !text_block.labels.is_empty() {
+                // runs larger than the block lower the first baseline (see below), a rule runs through the middle of its marker
+                let baseline = |font_size: f32| match (text_block.rule.is_some(), line.body.is_empty()) {
+                    (true, _) => self.y - font_size * 0.35,
+                    (false, true) => self.y,
+                    (false, false) => self.y - (line.font_size - text_block.font_size)
+                };
+                let page = self.pages.last_mut().ok_or(RenderError::MissingPage)?;
+                let content = page.contents.last_mut().ok_or(RenderError::MissingPage)?;
+
+                for label in &text_block.labels {
+                    let ref_obj = Writer::font_reference(&font_map, label.font_family, &TextStyle::new(label.font_size))?;
+                    let label_x = self.page_margins.left + label.indent - label.font_size * 0.5 - label.width;
+                    let label_bytes = Writer::encode(ref_obj, &label.text, &mut self.glyph_usage);
+
+                    content.content.begin_text();
+                    content.content.set_font(ref_obj.name, label.font_size);
+                    content.content.next_line(label_x, baseline(label.font_size));
+                    content.content.show(Str(&label_bytes));
+                    content.content.end_text();
+                }
+            }
+
+            // a rule block has a single line, the rule drawn in its place
+            if let Some(rule) = &text_block.rule {
+                let left = self.page_margins.left + block_indent;
+                let right = self.page_width - self.page_margins.right;
+                let page = self.pages.last_mut().ok_or(RenderError::MissingPage)?;
+                let target = &mut page.contents.last_mut().ok_or(RenderError::MissingPage)?.content;
+
+                Writer::draw_horizontal_rule(target, rule, left, right, self.y);
+                self.y -= rule.thickness;
+                continue;
+            }
+
+            // the background is painted line by line, so a block split across pages is boxed on each of them
+            if let Some(background) = text_block.background {
+                let height = match line.body.is_empty() {
+                    true => text_block.font_size * 1.5,
+                    false => line.font_size * 1.5
+                };
+                // the box is padded above the first line and below the last one
+                let top = self.y + text_block.font_size + if index == 0 { text_block.padding } else { 0.0 };
+                let bottom = self.y + text_block.font_size - height - if index + 1 == text_block.lines.len() { text_block.padding } else { 0.0 };
+                let left = self.page_margins.left + block_indent - text_block.padding;
+                let right = self.page_width - self.page_margins.right;
+
+                let page = self.pages.last_mut().ok_or(RenderError::MissingPage)?;
+                let target = &mut page.contents.last_mut().ok_or(RenderError::MissingPage)?.content;
+
+                target.set_fill_rgb(background.red, background.green, background.blue);
+                target.rect(left, bottom, right - left, top - bottom);
+                target.fill_nonzero();
+                target.set_fill_rgb(0.0, 0.0, 0.0);
+            }
+
+            // each line leaves its largest run's size above the baseline and half of it below, so mixed sizes never overlap
+            // `self.y` is the baseline of a line set entirely at the block size
+            if !line.body.is_empty() {
+                self.y -= line.font_size - text_block.font_size;
+            }
+
 
             // line break
             if line.body.is_empty() {
@@ -116,203 +374,354 @@ impl Writer <'_> {
 
             self.x = 0.0;
             self.x += block_indent;
-            self.x += self.page_margin;
+            self.x += self.page_margins.left;
             self.x += line.offset;
 
-            debug_assert!(self.x >= self.page_margin);
-            debug_assert!(self.x <= self.page_width - self.page_margin);
-            debug_assert!(self.y >= self.page_margin);
-            debug_assert!(self.y <= self.page_height - self.page_margin);
-
-            let page_opt = &mut self.pages.last_mut();
-
-            if let Some(page) = page_opt {
-                if let Some(content) = page.contents.pop() {
-                    let mut target = content.content;
-
-                    target.begin_text();
-                    target.next_line(self.x, self.y);
-
-                    let line_start_index = self.x;
-            
-                    for word in &line.body {
-                        // a `Word`` object can't have empty text
-                        // if it is, there is likely a bug in `Doc::render_text_block()`
-                        debug_assert!(word.text.is_empty() == false);
-                        debug_assert!(self.x >= self.page_margin);
-                        debug_assert!(self.x <= self.page_width - self.page_margin);
-
-                        match word.font_style {
-                            Style::Normal => if let Some(ref_obj) = font_map.get("times-normal") { target.set_font(ref_obj.name, text_block.font_size); },
-                            Style::Underline => if let Some(ref_obj) = font_map.get("times-normal") { target.set_font(ref_obj.name, text_block.font_size); },
-                            Style::Strikethrough => if let Some(ref_obj) =  font_map.get("times-normal") { target.set_font(ref_obj.name, text_block.font_size); },
-                            Style::Italic => if let Some(ref_obj) =  font_map.get("times-italic") { target.set_font(ref_obj.name, text_block.font_size); },
-                            Style::ItalicUnderline => if let Some(ref_obj) =  font_map.get("times-italic") { target.set_font(ref_obj.name, text_block.font_size); },
-                            Style::ItalicStrikethrough => if let Some(ref_obj) =  font_map.get("times-italic") { target.set_font(ref_obj.name, text_block.font_size); },
-                            Style::Bold => if let Some(ref_obj) =  font_map.get("times-bold") { target.set_font(ref_obj.name, text_block.font_size); },
-                            Style::BoldUnderline => if let Some(ref_obj) =  font_map.get("times-bold") { target.set_font(ref_obj.name, text_block.font_size); },
-                            Style::BoldStrikethrough => if let Some(ref_obj) =  font_map.get("times-bold") { target.set_font(ref_obj.name, text_block.font_size); },
-                            Style::BoldItalicUnderline => if let Some(ref_obj) =  font_map.get("times-bold-italic") { target.set_font(ref_obj.name, text_block.font_size); },
-                            Style::BoldItalicStrikethrough => if let Some(ref_obj) =  font_map.get("times-bold-italic") { target.set_font(ref_obj.name, text_block.font_size); },
-                            Style::BoldItalic => if let Some(ref_obj) =  font_map.get("times-bold-italic") { target.set_font(ref_obj.name, text_block.font_size); }
-                        };
-
-                        target.show(Str(word.text.as_bytes()));
-                        target.next_line(word.width + word.offset, 0.0);
-
-                        self.x += word.width + word.offset;
-                    }
+            // text positioned off the page would silently disappear from the rendered PDF
+            if self.x < 0.0 || self.x > self.page_width || self.y < 0.0 || self.y > self.page_height {
+                return Err(RenderError::OutOfBounds { x: self.x, y: self.y });
+            }
 
-                    let ending_index = self.x;
-
-                    /* *************************** */
-
-                    self.x = line_start_index;
-                    target.move_to(self.x, self.y);
-                    
-                    let mut underline_flag = false;
-                    let mut strikethrough_flag = false;
-                    let mut underline_points:Vec<f32> = Vec::with_capacity(10);
-                    let mut strikethrough_points:Vec<f32> = Vec::with_capacity(2);
-                    let mut last_offset = 0.0;
-
-                    for word in &line.body {
-                        debug_assert!(self.x >= self.page_margin);
-                        debug_assert!(self.x <= self.page_width - self.page_margin);
-
-                        match word.font_style {
-                            Style::Underline => {
-                                if !underline_flag {
-                                    underline_points.push(self.x);
-                                    underline_flag = true;
-                                }
-                            },
-                            Style::ItalicUnderline => {
-                                if !underline_flag {
-                                    underline_points.push(self.x);
-                                    underline_flag = true;
-                                }
-                            },
-                            Style::BoldUnderline => {
-                                if !underline_flag {
-                                    underline_points.push(self.x);
-                                    underline_flag = true;
-                                }
-                            },
-                            Style::BoldItalicUnderline => {
-                                if !underline_flag {
-                                    underline_points.push(self.x);
-                                    underline_flag = true;
-                                }
-                            },
-                            _=> {
-                                if underline_flag {
-                                    underline_points.push(self.x - word.offset);
-                                    underline_flag = false;
-                                }
-                            }
-                        }
-
-                        match word.font_style {
-                            Style::Strikethrough => {
-                                if !strikethrough_flag {
-                                    strikethrough_points.push(self.x);
-                                    strikethrough_flag = true;
-                                }
-                            },
-                            Style::BoldStrikethrough => {
-                                if !strikethrough_flag {
-                                    strikethrough_points.push(self.x);
-                                    strikethrough_flag = true;
-                                }
-                            },
-                            Style::ItalicStrikethrough => {
-                                if !strikethrough_flag {
-                                    strikethrough_points.push(self.x);
-                                    strikethrough_flag = true;
-                                }
-                            },
-                            Style::BoldItalicStrikethrough => {
-                                if !strikethrough_flag {
-                                    strikethrough_points.push(self.x);
-                                    strikethrough_flag = true;
-                                }
-                            },
-                            _=> {
-                                if strikethrough_flag {
-                                    strikethrough_points.push(self.x - word.offset);
-                                    strikethrough_flag = false;
-                                }
-                            }
-                        }
-
-                        last_offset = word.offset;
-
-                        self.x += word.offset;
-                        self.x += word.width;
-                    }
+            let page = self.pages.last_mut().ok_or(RenderError::MissingPage)?;
+            let content = page.contents.pop().ok_or(RenderError::MissingPage)?;
+            let mut target = content.content;
 
-                    if underline_flag {
-                        underline_points.push(self.x - last_offset);
-                    }
+            // a clipped line runs on past the block, only the part left of its right padding shows
+            if text_block.clip {
+                let left = self.page_margins.left + block_indent - text_block.padding;
+                let right = self.page_width - self.page_margins.right - text_block.padding;
 
-                    if strikethrough_flag {
-                        strikethrough_points.push(self.x - last_offset);
-                    }
+                target.save_state();
+                target.rect(left, 0.0, right - left, self.page_height);
+                target.clip_nonzero();
+                target.end_path();
+            }
 
-                    if underline_points.len() > 1 {
-                        for (index,point) in underline_points.iter().enumerate() {
-                            let even = index % 2 == 0;
+            // highlights are painted first so the text sits on top of them
+            Writer::draw_highlights(&mut target, line, self.x, self.y);
 
-                            if even {
-                                target.move_to(*point, self.y - (text_block.font_size / 3.3));
-                            } else {
-                                target.line_to(*point, self.y - (text_block.font_size / 3.3));
-                            }
-                        }
-                    }
+            target.begin_text();
+            target.next_line(self.x, self.y);
 
-                    if strikethrough_points.len() > 1 {
-                        for (index,point) in strikethrough_points.iter().enumerate() {
-                            let even = index % 2 == 0;
+            let line_start_index = self.x;
+            let mut fill = Color::BLACK;
+            let mut rise: f32 = 0.0;
+            let mut current_font: Option<(Ref, f32)> = None;
+            // continuous text advances by itself, positioned words are moved to one by one
+            let operators = self.justification.spacing == JustifySpacing::Operators;
+            // distance continuous text has advanced since the last `Td`
+            let mut moved: f32 = 0.0;
 
-                            if even {
-                                target.move_to(*point, self.y - (text_block.font_size / 3.3));
-                            } else {
-                                target.line_to(*point, self.y - (text_block.font_size / 3.3));
-                            }
-                        }
-                    }
+            if line.char_spacing != 0.0 {
+                target.set_char_spacing(line.char_spacing);
+            }
 
-                    target.move_to(ending_index, self.y);
+            if line.word_spacing != 0.0 {
+                target.set_word_spacing(line.word_spacing);
+            }
+    
+            for word in &line.body {
+                // a `Word`` object can't have empty text
+                // if it is, there is likely a bug in `Doc::layout_text_block()`
+                debug_assert!(!word.text.is_empty());
+
+                let ref_obj = Writer::font_reference(&font_map, text_block.font_family, &word.text_style)?;
+                let font = (ref_obj.id, word.text_style.font_size());
+
+                // words joined across marks and neighbouring segments usually share a font
+                if current_font != Some(font) {
+                    target.set_font(ref_obj.name, font.1);
+                    current_font = Some(font);
+                }
 
-                    self.y -= text_block.font_size * 1.5;
+                let color = word.text_style.color.unwrap_or(Color::BLACK);
 
-                    target.stroke();
-                    target.end_text();
+                if color != fill {
+                    target.set_fill_rgb(color.red, color.green, color.blue);
+                    fill = color;
+                }
 
-                    let new_content = PageContent {
-                        content_id: content.content_id,
-                        content: target
-                    };
+                if word.text_style.rise() != rise {
+                    rise = word.text_style.rise();
+                    target.set_rise(rise);
+                }
 
-                    page.contents.push(new_content);
+                target.show(Str(&Writer::encode(ref_obj, word.text, &mut self.glyph_usage)));
+
+                match operators {
+                    true if !word.space.is_empty() => {
+                        target.show(Str(&Writer::encode(ref_obj, word.space, &mut self.glyph_usage)));
+                        moved += word.width + word.offset;
+                    },
+                    // gaps without a space to draw (code block tab stops) are moved over
+                    true if word.offset != 0.0 => {
+                        target.next_line(moved + word.width + word.offset, 0.0);
+                        moved = 0.0;
+                    },
+                    true => moved += word.width,
+                    false => { target.next_line(word.width + word.offset, 0.0); }
                 }
+
+                self.x += word.width + word.offset;
+            }
+
+            // every line starts out black and on the baseline
+            if fill != Color::BLACK {
+                target.set_fill_rgb(0.0, 0.0, 0.0);
+            }
+
+            if rise != 0.0 {
+                target.set_rise(0.0);
+            }
+
+            if line.char_spacing != 0.0 {
+                target.set_char_spacing(0.0);
+            }
+
+            if line.word_spacing != 0.0 {
+                target.set_word_spacing(0.0);
+            }
+
+            target.end_text();
+
+            // underlines and strikethroughs are paths, which can't be drawn inside a text object
+            // both take the colour of their run, strikethroughs follow superscript and subscript runs up and down
+            let baseline = self.y;
+            let underline_y = baseline - line.font_size / 3.3;
+            let underlines = Writer::runs(line, line_start_index, |word| word.text_style.underline.then_some(word.text_style.color))
+                .into_iter()
+                .map(|(color, start, end, _)| (color, start, end, underline_y));
+            let strikes = Writer::runs(line, line_start_index, |word| word.text_style.strike.then_some((word.text_style.color, word.text_style.rise())))
+                .into_iter()
+                .map(|((color, rise), start, end, font_size)| (color, start, end, baseline + rise + font_size * 0.3));
+
+            Writer::draw_rules(&mut target, underlines.chain(strikes));
+
+            if text_block.clip {
+                target.restore_state();
+            }
+
+            let links = Writer::runs(line, line_start_index, |word| word.text_style.link);
+
+            self.y -= line.font_size * 0.5 + text_block.font_size;
+
+            let new_content = PageContent {
+                content_id: content.content_id,
+                content: target
+            };
+
+            page.contents.push(new_content);
+
+            // a link wrapping across lines (or pages) gets an annotation over its part of each line
+            for (href, start, end, font_size) in links {
+                let link = LinkAnnotation {
+                    id: self.alloc.bump(),
+                    rect: Rect::new(start, baseline - font_size * 0.25, end, baseline + font_size * 0.95),
+                    uri: Writer::encode_uri(href)
+                };
+
+                self.pages.last_mut().ok_or(RenderError::MissingPage)?.links.push(link);
             }
         }
+
+        if last {
+            self.feed(text_block.post_block_offset);
+        }
+
+        Ok(())
+    }
+
+    /// groups consecutive words sharing a value (highlight colour, link...) into runs of value, start, end and largest font size
+    /// - a continuing run stretches over the space before the word
+    /// - a run wrapping onto the next line is continued by that line's runs
+    fn runs<'w, T: PartialEq + Copy>(line: &Line<'w>, line_start: f32, value: impl Fn(&Word<'w>) -> Option<T>) -> Vec<(T, f32, f32, f32)> {
+        let mut runs: Vec<(T, f32, f32, f32)> = Vec::new();
+        let mut previous: Option<T> = None;
+        let mut x = line_start;
+
+        for word in &line.body {
+            let current = value(word);
+
+            match current {
+                Some(current) if previous == Some(current) => {
+                    if let Some((_, _, end, font_size)) = runs.last_mut() {
+                        *end = x + word.width;
+                        *font_size = font_size.max(word.text_style.font_size());
+                    }
+                },
+                Some(current) => runs.push((current, x, x + word.width, word.text_style.font_size())),
+                None => {}
+            }
+
+            previous = current;
+            x += word.width + word.offset;
+        }
+
+        runs
     }
 
-    pub fn get_char_width(&self, ch: &char,  font_size: f32, font_style: &Style, search_string: &str) -> f32 {
-        const DEFAULT_FONT_WIDTH: f32 = 55.0;
-        const DEFAULT_FONT_SIZE: f32 = 18.0;
+    /// paints a rectangle behind each run of highlighted words on a line
+    fn draw_highlights(target: &mut Content, line: &Line, line_start: f32, baseline: f32) {
+        let runs = Writer::runs(line, line_start, |word| word.text_style.highlight);
 
-        // vefify search string returns an existing font family
-        debug_assert!(self.font_family.contains_key(search_string));
+        if runs.is_empty() {
+            return;
+        }
 
-        if let Some(font) = self.font_family.get(search_string) {
-            font.char_width(ch, font_style, font_size)
-        } else {
-            font_size / DEFAULT_FONT_SIZE * DEFAULT_FONT_WIDTH
+        for (color, start, end, font_size) in runs.iter() {
+            target.set_fill_rgb(color.red, color.green, color.blue);
+            target.rect(*start, baseline - font_size * 0.25, end - start, font_size * 1.2);
+            target.fill_nonzero();
         }
+
+        target.set_fill_rgb(0.0, 0.0, 0.0);
+    }
+
+    /// strokes a horizontal rule (underline or strikethrough) of each colour, start, end and height, black when no colour is set
+    fn draw_rules(target: &mut Content, rules: impl Iterator<Item = (Option<Color>, f32, f32, f32)>) {
+        let mut stroke = Color::BLACK;
+
+        for (color, start, end, y) in rules {
+            let color = color.unwrap_or(Color::BLACK);
+
+            if color != stroke {
+                target.set_stroke_rgb(color.red, color.green, color.blue);
+                stroke = color;
+            }
+
+            target.move_to(start, y);
+            target.line_to(end, y);
+            target.stroke();
+        }
+
+        if stroke != Color::BLACK {
+            target.set_stroke_rgb(0.0, 0.0, 0.0);
+        }
+    }
+
+    /// strokes each quote rule from its top down to its bottom on the current page
+    fn draw_quote_rules(&mut self, quote_rules: impl Iterator<Item = (QuoteRule, f32, f32)>) -> Result<(), RenderError> {
+        let left = self.page_margins.left;
+        let page = self.pages.last_mut().ok_or(RenderError::MissingPage)?;
+        let target = &mut page.contents.last_mut().ok_or(RenderError::MissingPage)?.content;
+        let mut drawn = false;
+
+        for (quote_rule, top, bottom) in quote_rules {
+            let x = left + quote_rule.x + quote_rule.width / 2.0;
+
+            target.set_stroke_rgb(quote_rule.color.red, quote_rule.color.green, quote_rule.color.blue);
+            target.set_line_width(quote_rule.width);
+            target.move_to(x, top);
+            target.line_to(x, bottom);
+            target.stroke();
+            drawn = true;
+        }
+
+        if drawn {
+            target.set_line_width(1.0);
+            target.set_stroke_rgb(0.0, 0.0, 0.0);
+        }
+
+        Ok(())
+    }
+
+    /// strokes a horizontal rule from `left` to `right` with its top edge at `top`
+    /// - round dots reach half their width past the ends of their path, so dotted rules are shortened by it
+    fn draw_horizontal_rule(target: &mut Content, rule: &RuleStyle, left: f32, right: f32, top: f32) {
+        let thickness = rule.thickness;
+        let inset = if rule.line_style == LineStyle::Dotted { thickness / 2.0 } else { 0.0 };
+        let (left, right) = (left + inset, right - inset);
+        let y = top - thickness / 2.0;
+
+        target.set_stroke_rgb(rule.color.red, rule.color.green, rule.color.blue);
+        target.set_line_width(thickness);
+
+        match rule.line_style {
+            LineStyle::Solid => {},
+            LineStyle::Dashed => { target.set_dash_pattern([thickness * 3.0, thickness * 2.0], 0.0); },
+            LineStyle::Dotted => {
+                target.set_line_cap(LineCapStyle::RoundCap);
+                target.set_dash_pattern([0.0, thickness * 2.0], 0.0);
+            }
+        }
+
+        target.move_to(left, y);
+        target.line_to(right, y);
+        target.stroke();
+
+        match rule.line_style {
+            LineStyle::Solid => {},
+            LineStyle::Dashed => { target.set_dash_pattern([], 0.0); },
+            LineStyle::Dotted => {
+                target.set_line_cap(LineCapStyle::ButtCap);
+                target.set_dash_pattern([], 0.0);
+            }
+        }
+
+        target.set_line_width(1.0);
+        target.set_stroke_rgb(0.0, 0.0, 0.0);
+    }
+
+    /// percent-encodes the bytes a PDF URI action can't hold (non-ASCII, spaces and control characters)
+    fn encode_uri(href: &str) -> String {
+        href.trim()
+            .bytes()
+            .map(|byte| match byte {
+                0x21..=0x7E => (byte as char).to_string(),
+                _ => format!("%{byte:02X}")
+            })
+            .collect()
+    }
+
+    /// returns the `FontReference` used to draw `text_style` text in `family`, or in the run's own family when it has one
+    /// - families without a matching variant (Symbol, ZapfDingbats, partial embedded or registered families) fall back to their normal variant
+    fn font_reference<'m, 'f>(font_map: &HashMap<&str, &'m FontReference<'f>>, family: FontFamily, text_style: &TextStyle) -> Result<&'m FontReference<'f>, RenderError> {
+        let family = text_style.family.unwrap_or(family);
+        let label = format!("{}-{}", family.key(), text_style.variant());
+
+        font_map.get(label.as_str())
+            .or_else(|| font_map.get(format!("{}-normal", family.key()).as_str()))
+            .copied()
+            .ok_or(RenderError::MissingFont(label))
+    }
+
+    /// encodes text for the font it is drawn with
+    /// - standard 14 fonts use a single byte encoding (WinAnsi, Symbol or ZapfDingbats), characters it can't represent are substituted
+    /// - embedded fonts use big endian glyph ids (Identity-H), each glyph is recorded for subsetting
+    fn encode(font_ref: &FontReference, text: &str, glyph_usage: &mut HashMap<Ref, BTreeMap<u16, char>>) -> Vec<u8> {
+        match font_ref.font_file {
+            Some(font) => Writer::encode_glyphs(font, text, glyph_usage.entry(font_ref.id).or_default()),
+            None => font_ref.encoding.encode(text)
+        }
+    }
+
+    /// encodes text as big endian glyph ids for an embedded (Identity-H) font and records each glyph for subsetting
+    /// - characters missing from the font are drawn with the `.notdef` glyph
+    fn encode_glyphs(font: &TrueTypeFont, text: &str, glyph_usage: &mut BTreeMap<u16, char>) -> Vec<u8> {
+        let mut encoded: Vec<u8> = Vec::with_capacity(text.len() * 2);
+
+        for ch in text.chars() {
+            let glyph_id = font.glyph_id(ch).unwrap_or(0);
+
+            glyph_usage.entry(glyph_id).or_insert(ch);
+            encoded.extend(glyph_id.to_be_bytes());
+        }
+
+        encoded
+    }
+
+    /// returns the width of a character in the embedded font family, errors when no family was embedded
+    pub fn get_embedded_char_width(&self, ch: &char, text_style: &TextStyle) -> Result<f32, RenderError> {
+        self.embedded_font
+            .map(|font| font.char_width(ch, text_style))
+            .ok_or_else(|| RenderError::MissingFont("embedded".to_string()))
+    }
+
+    /// returns the width of a character for a registered font family, errors when the family was never registered
+    pub fn get_char_width(&self, ch: &char, text_style: &TextStyle, search_string: &str) -> Result<f32, RenderError> {
+        self.font_family
+            .get(search_string)
+            .map(|font| font.char_width(ch, text_style))
+            .ok_or_else(|| RenderError::MissingFont(search_string.to_string()))
     }
 }